@@ -379,6 +379,7 @@ pub use ffi::{
     ncplane_move_family_above,
     ncplane_move_family_below,
     ncplane_move_yx,
+    ncplane_name,
     ncplane_notcurses,
     ncplane_notcurses_const,
     ncplane_off_styles,
@@ -421,6 +422,7 @@ pub use ffi::{
     ncplane_set_fg_palindex,
     ncplane_set_fg_rgb,
     ncplane_set_fg_rgb8,
+    ncplane_set_name,
     ncplane_set_resizecb,
     ncplane_set_scrolling,
     ncplane_set_styles,
@@ -0,0 +1,42 @@
+//! `NcDecodedCell`
+
+use crate::{NcCell, NcChannels, NcPlane, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+/// A fully owned snapshot of an [`NcCell`], independent from its plane.
+///
+/// Unlike an `NcCell`, whose `EGC` may live in the egcpool of its plane, and
+/// which gets invalidated when that plane is destroyed, this can be stored for
+/// as long as needed.
+///
+/// It can be obtained with [`NcPlane.read_cell`].
+///
+/// [`NcPlane.read_cell`]: crate::NcPlane#method.read_cell
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcDecodedCell {
+    /// The extended grapheme cluster.
+    pub egc: String,
+
+    /// The styles.
+    pub style: NcStyle,
+
+    /// The foreground & background channels.
+    pub channels: NcChannels,
+
+    /// The number of columns occupied.
+    pub width: u8,
+}
+
+impl NcDecodedCell {
+    /// New `NcDecodedCell` from a `cell` associated to `plane`.
+    pub fn from_cell(plane: &NcPlane, cell: &NcCell) -> Self {
+        Self {
+            egc: cell.egc(plane).to_string(),
+            style: cell.styles(),
+            channels: cell.channels(),
+            width: cell.cols(),
+        }
+    }
+}
@@ -58,6 +58,50 @@ impl NcCell {
         Ok(cell)
     }
 
+    /// New `NcCell`, from a [`char`], with the provided `style` and `channels`.
+    ///
+    /// Expects a plane where to save the extra data if it's greater than 4 bytes.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_char_styled(
+        plane: &mut NcPlane,
+        ch: char,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<Self> {
+        let mut buf = [0; 4];
+        Self::from_str_styled(plane, ch.encode_utf8(&mut buf), style, channels)
+    }
+
+    /// New `NcCell`, from a [`&str`], with the provided `style` and `channels`.
+    ///
+    /// Expects a plane where to save the extra data if it's greater than 4 bytes.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_str_styled(
+        plane: &mut NcPlane,
+        string: &str,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<Self> {
+        let mut cell = Self::new();
+        Self::prime(plane, &mut cell, string, style, channels)?;
+        Ok(cell)
+    }
+
+    /// New blank `NcCell`, containing a space, with no style and the default
+    /// channels.
+    ///
+    /// It doesn't need to be released, since it holds no extra data.
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn blank() -> Self {
+        let mut cell = Self::from_char7b(' ').unwrap();
+        cell.set_channels(NcChannels::with_default());
+        cell
+    }
+
     /// New empty `NcCell`.
     #[inline]
     pub fn new() -> Self {
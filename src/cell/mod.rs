@@ -72,9 +72,12 @@
 #[cfg(test)]
 mod test;
 
+mod decoded;
 mod methods;
 pub(crate) mod reimplemented;
 
+pub use decoded::NcDecodedCell;
+
 // NcCell
 /// A coordinate on an [`NcPlane`][crate::NcPlane] storing 128 bits of data.
 ///
@@ -16,11 +16,11 @@ use crate::rstring;
 #[cfg(all(not(feature = "std"), feature = "libc"))]
 use alloc::string::String;
 
-const NCBOXLIGHT: &str = "┌┐└┘─│";
-const NCBOXHEAVY: &str = "┏┓┗┛━┃";
-const NCBOXROUND: &str = "╭╮╰╯─│";
-const NCBOXDOUBLE: &str = "╔╗╚╝═║";
-const NCBOXASCII: &str = "/\\\\/-|";
+pub(crate) const NCBOXLIGHT: &str = "┌┐└┘─│";
+pub(crate) const NCBOXHEAVY: &str = "┏┓┗┛━┃";
+pub(crate) const NCBOXROUND: &str = "╭╮╰╯─│";
+pub(crate) const NCBOXDOUBLE: &str = "╔╗╚╝═║";
+pub(crate) const NCBOXASCII: &str = "/\\\\/-|";
 const NCBOXOUTER: &str = "🭽🭾🭼🭿▁🭵🭶🭰";
 
 // Channels -----------------------------------------------------------------------
@@ -496,32 +496,30 @@ pub fn nccells_load_box(
 ) -> NcResult_i32 {
     let (style, channels) = (style.into(), channels.into());
 
-    // CHECK: mutable copy for pointer arithmetics
+    // the remaining EGCs to load
+    let mut gclu = gcluster;
 
-    let cs = cstring![gcluster];
-    let mut gclu = cs.as_ptr();
-
-    let mut ulen: NcResult_i32 = nccell_prime(plane, ul, gcluster, style, channels);
+    let mut ulen: NcResult_i32 = nccell_prime(plane, ul, gclu, style, channels);
 
     if ulen > 0 {
-        gclu = unsafe { gclu.offset(ulen as isize) };
-        ulen = nccell_prime(plane, ur, gcluster, style, channels);
+        gclu = gclu.get(ulen as usize..).unwrap_or_default();
+        ulen = nccell_prime(plane, ur, gclu, style, channels);
 
         if ulen > 0 {
-            gclu = unsafe { gclu.offset(ulen as isize) };
-            ulen = nccell_prime(plane, ll, gcluster, style, channels);
+            gclu = gclu.get(ulen as usize..).unwrap_or_default();
+            ulen = nccell_prime(plane, ll, gclu, style, channels);
 
             if ulen > 0 {
-                gclu = unsafe { gclu.offset(ulen as isize) };
-                ulen = nccell_prime(plane, lr, gcluster, style, channels);
+                gclu = gclu.get(ulen as usize..).unwrap_or_default();
+                ulen = nccell_prime(plane, lr, gclu, style, channels);
 
                 if ulen > 0 {
-                    gclu = unsafe { gclu.offset(ulen as isize) };
-                    ulen = nccell_prime(plane, hl, gcluster, style, channels);
+                    gclu = gclu.get(ulen as usize..).unwrap_or_default();
+                    ulen = nccell_prime(plane, hl, gclu, style, channels);
 
                     if ulen > 0 {
-                        let _gclu = unsafe { gclu.offset(ulen as isize) };
-                        ulen = nccell_prime(plane, vl, gcluster, style, channels);
+                        gclu = gclu.get(ulen as usize..).unwrap_or_default();
+                        ulen = nccell_prime(plane, vl, gclu, style, channels);
 
                         if ulen > 0 {
                             return c_api::NCRESULT_OK;
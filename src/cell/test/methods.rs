@@ -65,6 +65,22 @@ fn constructors() -> crate::NcResult<()> {
     assert_eq![NcStyle::Underline, cell.styles()];
     assert_eq![NcChannels::from_rgb_both(0x112233), cell.channels()];
 
+    // styled constructors
+    let mut styled = NcCell::from_char_styled(plane, '╭', NcStyle::Bold, 0x112233_445566)?;
+    assert_eq![NcStyle::Bold, styled.styles()];
+    assert_eq![NcChannels::from(0x112233_445566_u64), styled.channels()];
+    assert_eq!["╭", styled.egc(plane)];
+    styled.release(plane);
+    let mut styled = NcCell::from_str_styled(plane, "─", NcStyle::None, 0)?;
+    assert_eq!["─", styled.egc(plane)];
+    styled.release(plane);
+
+    // blank
+    let blank = NcCell::blank();
+    assert_eq![NcStyle::None, blank.styles()];
+    assert![blank.fg_default_p() && blank.bg_default_p()];
+    assert_eq![" ", blank.egc(plane)];
+
     // cleanup
     cell.release(plane);
     plane.destroy()?;
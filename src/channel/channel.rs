@@ -37,7 +37,7 @@ use crate::{
 pub struct NcChannel(pub NcChannel_u32);
 
 mod core_impls {
-    use super::{NcChannel, NcChannel_u32};
+    use super::{NcChannel, NcChannel_u32, NcRgb};
 
     impl Default for NcChannel {
         fn default() -> Self {
@@ -69,6 +69,12 @@ mod core_impls {
         }
     }
 
+    impl From<NcRgb> for NcChannel {
+        fn from(rgb: NcRgb) -> Self {
+            Self::from_rgb(rgb)
+        }
+    }
+
     crate::from_primitive![NcChannel, NcChannel_u32];
     crate::unit_impl_from![NcChannel, NcChannel_u32];
     crate::unit_impl_fmt![bases+display; NcChannel];
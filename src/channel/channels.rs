@@ -239,6 +239,52 @@ impl NcChannels {
     }
 }
 
+/// # NcChannels non-mutating methods
+///
+/// These return a modified copy, which allows building a value in one
+/// expression, e.g. to pass it to [`NcPlane.set_channels`].
+///
+/// [`NcPlane.set_channels`]: crate::NcPlane#method.set_channels
+impl NcChannels {
+    /// Returns a copy with the foreground set to `rgb`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_fg_rgb(self, rgb: impl Into<NcRgb>) -> Self {
+        let mut channels = self;
+        channels.set_fg_rgb(rgb)
+    }
+
+    /// Returns a copy with the background set to `rgb`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_bg_rgb(self, rgb: impl Into<NcRgb>) -> Self {
+        let mut channels = self;
+        channels.set_bg_rgb(rgb)
+    }
+
+    /// Returns a copy with the foreground [`NcAlpha`] set to `alpha`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_fg_alpha(self, alpha: impl Into<NcAlpha>) -> Self {
+        let mut channels = self;
+        // can't fail: every NcAlpha is valid for the foreground
+        let _ = channels.set_fg_alpha(alpha);
+        channels
+    }
+
+    /// Returns a copy with the background [`NcAlpha`] set to `alpha`.
+    ///
+    /// The background doesn't support [`NcAlpha::HighContrast`], in which
+    /// case the copy is returned unchanged.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_bg_alpha(self, alpha: impl Into<NcAlpha>) -> Self {
+        let mut channels = self;
+        let _ = channels.set_bg_alpha(alpha);
+        channels
+    }
+}
+
 /// # NcChannels methods
 impl NcChannels {
     // NcChannel
@@ -1,10 +1,56 @@
 //! Test `NcChannel*` methods and associated functions.
 
-// use crate::{NcChannel, NcChannels};
-//
-// use serial_test::serial;
-//
-// #[test]
-// #[serial]
-// fn () {
-// }
+use crate::{
+    c_api::{self, NcChannel_u32, NcChannels_u64},
+    NcAlpha, NcChannel, NcChannels, NcRgb,
+};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn channels_with_rgb() {
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+
+    let mut c: NcChannels_u64 = channels.0;
+    c_api::ncchannels_set_fg_rgb(&mut c, 0xAABBCC_u32);
+    assert_eq![c, channels.with_fg_rgb(0xAABBCC).0];
+
+    let mut c: NcChannels_u64 = channels.0;
+    c_api::ncchannels_set_bg_rgb(&mut c, 0xAABBCC_u32);
+    assert_eq![c, channels.with_bg_rgb(0xAABBCC).0];
+
+    // the original value is unchanged
+    assert_eq![NcChannels::from_rgb(0x112233, 0x445566), channels];
+}
+
+#[test]
+#[serial]
+fn channels_with_alpha() {
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+
+    let mut c: NcChannels_u64 = channels.0;
+    c_api::ncchannels_set_fg_alpha(&mut c, NcAlpha::HighContrast);
+    assert_eq![c, channels.with_fg_alpha(NcAlpha::HighContrast).0];
+
+    let mut c: NcChannels_u64 = channels.0;
+    c_api::ncchannels_set_bg_alpha(&mut c, NcAlpha::Blend);
+    assert_eq![c, channels.with_bg_alpha(NcAlpha::Blend).0];
+
+    assert_eq![channels, channels.with_bg_alpha(NcAlpha::HighContrast)];
+}
+
+#[test]
+#[serial]
+fn channel_from_rgb() {
+    let mut c: NcChannel_u32 = 0;
+    c_api::ncchannel_set_rgb8(&mut c, 0x11, 0x22, 0x33);
+    assert_eq![c, NcChannel::from((0x11, 0x22, 0x33)).0];
+    assert_eq![c, NcChannel::from(NcRgb(0x112233)).0];
+    assert![!NcChannel::from(NcRgb(0)).default_p()];
+
+    let mut c: NcChannels_u64 = 0;
+    c_api::ncchannels_set_fg_rgb(&mut c, 0x112233_u32);
+    c_api::ncchannels_set_bg_rgb(&mut c, 0x445566_u32);
+    assert_eq![c, NcChannels::from_rgb(NcRgb(0x112233), NcRgb(0x445566)).0];
+}
@@ -0,0 +1,69 @@
+//! `NcDirectGuard`
+
+use core::{
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{NcDirect, NcDirectFlag, NcResult};
+
+/// An owned [`NcDirect`] context that gets stopped when dropped.
+///
+/// This restores the terminal on early returns, e.g. when using `?`.
+/// It dereferences to [`NcDirect`], so all of its methods are available.
+///
+/// # Panics
+/// If the stack unwinds, the context is stopped while dropping the guard,
+/// but with `panic = "abort"` destructors don't run and the terminal is not
+/// restored.
+///
+/// # Double stop
+/// Don't call [`NcDirect::stop`] through the guard, since it would be
+/// stopped again when dropped. Use [`NcDirectGuard::stop`] instead, which
+/// consumes the guard, in order to handle any error.
+#[derive(Debug)]
+pub struct NcDirectGuard {
+    ptr: NonNull<NcDirect>,
+}
+
+impl NcDirectGuard {
+    /// New `NcDirectGuard` with optional flags.
+    ///
+    /// # Safety
+    /// You must not create multiple `NcDirect` instances at the same time, on
+    /// the same thread.
+    ///
+    /// *C style function: [ncdirect_init()][crate::c_api::ncdirect_init].*
+    pub unsafe fn with_flags(flags: impl Into<NcDirectFlag>) -> NcResult<Self> {
+        let ncd = NcDirect::with_flags(flags)?;
+        Ok(Self { ptr: NonNull::from(ncd) })
+    }
+
+    /// Stops the context, returning the result.
+    ///
+    /// *C style function: [ncdirect_stop()][crate::c_api::ncdirect_stop].*
+    pub fn stop(self) -> NcResult<()> {
+        let mut guard = ManuallyDrop::new(self);
+        unsafe { guard.ptr.as_mut().stop() }
+    }
+}
+
+impl Deref for NcDirectGuard {
+    type Target = NcDirect;
+    fn deref(&self) -> &NcDirect {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl DerefMut for NcDirectGuard {
+    fn deref_mut(&mut self) -> &mut NcDirect {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl Drop for NcDirectGuard {
+    fn drop(&mut self) {
+        let _ = unsafe { self.ptr.as_mut().stop() };
+    }
+}
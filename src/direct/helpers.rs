@@ -0,0 +1,30 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+/// The terminals known to support OSC 8 hyperlinks, matched by prefix
+/// against the name returned by `ncdirect_detected_terminal`.
+const HYPERLINK_TERMINALS: [&str; 8] = [
+    "alacritty",
+    "contour",
+    "foot",
+    "iterm2",
+    "kitty",
+    "konsole",
+    "vte",
+    "wezterm",
+];
+
+/// Returns true if the `terminal` is known to support OSC 8 hyperlinks.
+pub(crate) fn supports_hyperlinks(terminal: &str) -> bool {
+    HYPERLINK_TERMINALS.iter().any(|name| {
+        terminal
+            .get(..name.len())
+            .map_or(false, |prefix| prefix.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Returns the `text` surrounded by the OSC 8 escape sequences that open
+/// and close a hyperlink pointing to `url`.
+pub(crate) fn hyperlink_sequence(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
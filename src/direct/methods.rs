@@ -5,10 +5,12 @@ use core::ptr::{null, null_mut};
 use crate::{
     c_api::{self, ffi::wchar_t},
     cstring, error, error_ref_mut, rstring_free, NcAlign, NcBlitter, NcCapabilities, NcChannels,
-    NcDirect, NcDirectFlag, NcError, NcFd, NcInput, NcPaletteIndex, NcPlane, NcResult, NcRgb,
-    NcScale, NcStyle, NcTime,
+    NcDirect, NcDirectFlag, NcDirectGuard, NcError, NcFd, NcInput, NcPaletteIndex, NcPlane,
+    NcResult, NcRgb, NcScale, NcStyle, NcTime,
 };
 
+use super::helpers::{hyperlink_sequence, supports_hyperlinks};
+
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String};
 
@@ -46,6 +48,20 @@ impl NcDirect {
         error_ref_mut![res, "Initializing NcDirect"]
     }
 
+    /// New `NcDirect` with the default options, owned by a guard that stops
+    /// it when dropped.
+    ///
+    /// See [`NcDirectGuard`] for more information.
+    ///
+    /// # Safety
+    /// You must not create multiple `NcDirect` instances at the same time, on
+    /// the same thread.
+    ///
+    /// *C style function: [ncdirect_init()][c_api::ncdirect_init].*
+    pub unsafe fn new_guarded() -> NcResult<NcDirectGuard> {
+        NcDirectGuard::with_flags(NcDirectFlag::None)
+    }
+
     /// Releases this `NcDirect` and any associated resources.
     ///
     /// # Safety
@@ -160,6 +176,25 @@ impl NcDirect {
             )
         ]
     }
+
+    /// Displays an image like [`render_image`][NcDirect#method.render_image],
+    /// returning the `(rows, cols)` it occupied, in cells.
+    ///
+    /// This allows positioning the following output after the image.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_image_sized(
+        &mut self,
+        filename: &str,
+        align: impl Into<NcAlign>,
+        blitter: impl Into<NcBlitter>,
+        scale: impl Into<NcScale>,
+    ) -> NcResult<(u32, u32)> {
+        let frame = self.render_frame(filename, blitter, scale, 0, 0)?;
+        let size = frame.dim_yx();
+        self.raster_frame(frame, align)?;
+        Ok(size)
+    }
 }
 
 /// ## NcDirect methods: `NcPaletteIndex`, `NcRgb`, `NcStyle` & default color
@@ -563,7 +598,8 @@ impl NcDirect {
 
 /// ## NcDirect methods: I/O
 impl NcDirect {
-    /// Returns a [char] representing a single unicode point.
+    /// Returns a [char] representing a single unicode point, or `None` if no
+    /// input was received before the `time` expired.
     ///
     /// If an event is processed, the return value is the `id` field from that
     /// event.
@@ -571,15 +607,48 @@ impl NcDirect {
     /// Provide a None `time` to block at length, a `time` of 0 for non-blocking
     /// operation, and otherwise a timespec to bound blocking.
     ///
+    /// See also [`read_input`][NcDirect#method.read_input], which is preferred
+    /// when the event details are needed.
+    ///
     /// *C style function: [ncdirect_get()][c_api::ncdirect_get].*
-    // CHECK returns 0 on a timeout.
-    pub fn get(&mut self, time: Option<NcTime>, input: Option<&mut NcInput>) -> NcResult<char> {
+    pub fn get(
+        &mut self,
+        time: Option<NcTime>,
+        input: Option<&mut NcInput>,
+    ) -> NcResult<Option<char>> {
         let ntime = if let Some(time) = time { &time as *const _ } else { null() };
         let ninput = if let Some(input) = input { input as *mut _ } else { null_mut() };
 
         let res = unsafe { c_api::ncdirect_get(self, ntime, ninput) };
-        core::char::from_u32(res)
-            .ok_or_else(|| NcError::with_msg(res as i32, &format!["Nc.get(time: {:?})", time]))
+        if res == 0 {
+            return Ok(None);
+        }
+        core::char::from_u32(res).map(Some).ok_or_else(|| {
+            NcError::with_msg(res as i32, &format!["NcDirect.get(time: {:?})", time])
+        })
+    }
+
+    /// Reads an input event, returning a new [`NcInput`] with its details,
+    /// including the `id`, the event type and the modifiers.
+    ///
+    /// Provide a None `time` to block at length, a `time` of 0 for non-blocking
+    /// operation, and otherwise a timespec to bound blocking.
+    ///
+    /// On a timeout the returned `NcInput` has an `id` of 0.
+    ///
+    /// *C style function: [ncdirect_get()][c_api::ncdirect_get].*
+    pub fn read_input(&mut self, time: Option<NcTime>) -> NcResult<NcInput> {
+        let ntime = if let Some(time) = time { &time as *const _ } else { null() };
+        let mut input = NcInput::new_empty();
+
+        let res = unsafe { c_api::ncdirect_get(self, ntime, &mut input) };
+        if res == u32::MAX {
+            return Err(NcError::with_msg(
+                res as i32,
+                &format!["NcDirect.read_input(time: {:?})", time],
+            ));
+        }
+        Ok(input)
     }
 
     /// Reads input blocking until an event is processed or a signal is received.
@@ -599,12 +668,16 @@ impl NcDirect {
     ///
     /// In the case of a valid read, a [`char`] is returned.
     ///
-    /// If no event is ready, returns 0.
+    /// If no event is ready, returns `None`.
     ///
     /// *C style function: [ncdirect_get_nblock()][c_api::ncdirect_get_nblock].*
-    pub fn get_nblock(&mut self, input: Option<&mut NcInput>) -> NcResult<char> {
+    pub fn get_nblock(&mut self, input: Option<&mut NcInput>) -> NcResult<Option<char>> {
         let res = c_api::ncdirect_get_nblock(self, input);
+        if res == 0 {
+            return Ok(None);
+        }
         core::char::from_u32(res as u32)
+            .map(Some)
             .ok_or_else(|| NcError::with_msg(res, "NcDirect.get_nblock()"))
     }
 
@@ -641,10 +714,70 @@ impl NcDirect {
         ]
     }
 
+    /// Outputs the `text` as a hyperlink pointing to `url`, according to the
+    /// `channels`.
+    ///
+    /// The text is surrounded by the OSC 8 escape sequences if the
+    /// [detected terminal][NcDirect#method.detected_terminal] is known to
+    /// support hyperlinks. Otherwise only the text is output.
+    ///
+    /// # Errors
+    /// If the `text` or the `url` contain control characters, which could
+    /// end the escape sequence early, or start a different one.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_hyperlink(
+        &mut self,
+        channels: impl Into<NcChannels>,
+        text: &str,
+        url: &str,
+    ) -> NcResult<()> {
+        if text.chars().chain(url.chars()).any(char::is_control) {
+            return Err(NcError::new_msg(&format!(
+                "NcDirect.put_hyperlink({:?}, {:?}): invalid control characters",
+                text, url
+            )));
+        }
+        if supports_hyperlinks(&self.detected_terminal()) {
+            self.putstr(channels, &hyperlink_sequence(text, url))
+        } else {
+            self.putstr(channels, text)
+        }
+    }
+
+    /// Sets the `channels` used when writing with the
+    /// [`fmt::Write`][core::fmt::Write] methods, e.g. with [`write!`].
+    ///
+    /// The channels become the current colors of this `NcDirect`, so they
+    /// also apply to any other output that doesn't set its own colors.
+    /// Until this is called, the text is written with the current colors.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_write_channels(&mut self, channels: impl Into<NcChannels>) -> NcResult<()> {
+        let channels = channels.into();
+        if channels.fg_default_p() {
+            self.set_fg_default()?;
+        } else if channels.fg_palindex_p() {
+            self.set_fg_palindex(channels.fg_palindex())?;
+        } else {
+            self.set_fg_rgb(channels.fg_rgb())?;
+        }
+        if channels.bg_default_p() {
+            self.set_bg_default()
+        } else if channels.bg_palindex_p() {
+            self.set_bg_palindex(channels.bg_palindex())
+        } else {
+            self.set_bg_rgb(channels.bg_rgb())
+        }
+    }
+
     /// Reads a (heap-allocated) line of text using the Readline library.
     ///
     /// Initializes Readline the first time it's called.
     ///
+    /// The line is copied into an owned `String` and the C allocation is
+    /// freed before returning, so the result stays valid across calls.
+    ///
     /// For input to be echoed to the terminal, it is necessary that the flag
     /// [`NcDirectFlag::INHIBIT_CBREAK`][0] be provided to the constructor.
     ///
@@ -812,3 +945,18 @@ impl NcDirect {
         )]
     }
 }
+
+/// Outputs the text with the current colors, using
+/// [`putstr`][NcDirect#method.putstr] with default channels.
+///
+/// The colors can be set with
+/// [`set_write_channels`][NcDirect#method.set_write_channels].
+impl core::fmt::Write for NcDirect {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        self.putstr(NcChannels::with_default(), s)
+            .map_err(|_| core::fmt::Error)
+    }
+}
@@ -81,9 +81,13 @@
 #[cfg(test)]
 mod test;
 
+mod guard;
+pub(crate) mod helpers;
 mod methods;
 pub(crate) mod reimplemented;
 
+pub use guard::NcDirectGuard;
+
 use c_api::NcDirectFlag_u64;
 
 /// Minimal notcurses instance for styling text.
@@ -0,0 +1,59 @@
+//! Test `NcDirect` methods and associated functions.
+
+use core::fmt::Write;
+
+use crate::{
+    c_api::{hyperlink_sequence, supports_hyperlinks},
+    NcChannels, NcDirect, NcError, NcResult,
+};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn put_hyperlink() -> NcResult<()> {
+    let mut direct = unsafe { NcDirect::new_guarded()? };
+    let channels = NcChannels::with_default();
+
+    direct.put_hyperlink(channels, "docs", "https://notcurses.com")?;
+    assert![direct
+        .put_hyperlink(channels, "docs", "https://x\x1b\\")
+        .is_err()];
+    assert![direct
+        .put_hyperlink(channels, "docs", "https://x\x07")
+        .is_err()];
+    assert![direct
+        .put_hyperlink(channels, "do\x1b]8;;https://y\x1b\\cs", "https://x")
+        .is_err()];
+    direct.flush()?;
+
+    direct.stop()
+}
+
+#[test]
+fn hyperlink_escapes() {
+    assert_eq![
+        "\x1b]8;;https://notcurses.com\x1b\\docs\x1b]8;;\x1b\\",
+        hyperlink_sequence("docs", "https://notcurses.com")
+    ];
+
+    assert![supports_hyperlinks("kitty 0.26.5")];
+    assert![supports_hyperlinks("WezTerm 20221119")];
+    assert![!supports_hyperlinks("xterm 372")];
+    assert![!supports_hyperlinks("")];
+}
+
+#[test]
+#[serial]
+fn fmt_write() -> NcResult<()> {
+    let mut direct = unsafe { NcDirect::new_guarded()? };
+
+    direct.set_write_channels(NcChannels::from_rgb(0xFF0000, 0x000000))?;
+    write!(direct, "{}-{}", 1, 2).map_err(|_| NcError::new_msg("write!"))?;
+
+    direct.set_write_channels(NcChannels::with_default())?;
+    writeln!(direct).map_err(|_| NcError::new_msg("writeln!"))?;
+    direct.flush()?;
+
+    direct.stop()
+}
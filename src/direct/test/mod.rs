@@ -1 +1,4 @@
+//! `NcDirect` tests.
 
+#[cfg(test)]
+mod methods;
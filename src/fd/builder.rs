@@ -0,0 +1,163 @@
+//! `NcSubprocBuilder`
+
+use core::ptr::null;
+
+use crate::{
+    c_api, cstring, error_ref_mut, NcFdPlaneCb, NcFdPlaneDoneCb, NcPlane, NcResult, NcSubproc,
+    NcSubprocOptions,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Builder object for [`NcSubproc`].
+///
+/// Can be constructed by calling [`NcSubproc::builder()`].
+///
+/// By default the first argument passed to the command is the binary name,
+/// the binary is not searched for in `$PATH`, and the environment is inherited.
+///
+/// [`NcSubproc::builder()`]: NcSubproc#method.builder
+#[derive(Clone, Debug)]
+pub struct NcSubprocBuilder {
+    pub(crate) bin: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) env: Option<Vec<String>>,
+    pub(crate) path: bool,
+    pub(crate) options: NcSubprocOptions,
+}
+
+/// # Constructors
+impl NcSubprocBuilder {
+    /// New `NcSubprocBuilder` for the provided binary.
+    pub fn new(bin: &str) -> Self {
+        Self {
+            bin: bin.to_string(),
+            args: Vec::new(),
+            env: None,
+            path: false,
+            options: NcSubprocOptions::new(core::ptr::null_mut(), 0, 0),
+        }
+    }
+
+    /// Finishes the building, spawning the command and returning the new
+    /// [`NcSubproc`] that streams its output into `plane`.
+    ///
+    /// Both the command's *stdout* and *stderr* are read, invoking `data_cb`
+    /// with the data read, and `done_cb` once the command has exited.
+    ///
+    /// Scrolling is enabled on `plane` in order for the output to never run
+    /// out of room.
+    ///
+    /// *C style functions: [ncsubproc_createv()][c_api::ncsubproc_createv],
+    /// [ncsubproc_createvp()][c_api::ncsubproc_createvp],
+    /// [ncsubproc_createvpe()][c_api::ncsubproc_createvpe].*
+    pub fn build<'a>(
+        self,
+        plane: &mut NcPlane,
+        data_cb: NcFdPlaneCb,
+        done_cb: NcFdPlaneDoneCb,
+    ) -> NcResult<&'a mut NcSubproc> {
+        let bin = cstring![self.bin.as_str()];
+
+        // the argument vector, starting with the binary name, null-terminated
+        let args: Vec<_> = core::iter::once(&self.bin)
+            .chain(self.args.iter())
+            .map(|a| cstring![a.as_str()])
+            .collect();
+        let mut argv: Vec<_> = args.iter().map(|a| a.as_ptr()).collect();
+        argv.push(null());
+
+        let env: Option<Vec<_>> = self
+            .env
+            .as_ref()
+            .map(|env| env.iter().map(|e| cstring![e.as_str()]).collect());
+        let envp: Option<Vec<_>> = env.as_ref().map(|env| {
+            env.iter()
+                .map(|e| e.as_ptr())
+                .chain(core::iter::once(null()))
+                .collect()
+        });
+
+        plane.set_scrolling(true);
+        let subproc = unsafe {
+            match (&envp, self.path) {
+                (Some(envp), _) => c_api::ncsubproc_createvpe(
+                    plane,
+                    &self.options,
+                    bin.as_ptr(),
+                    argv.as_ptr(),
+                    envp.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+                (None, true) => c_api::ncsubproc_createvp(
+                    plane,
+                    &self.options,
+                    bin.as_ptr(),
+                    argv.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+                (None, false) => c_api::ncsubproc_createv(
+                    plane,
+                    &self.options,
+                    bin.as_ptr(),
+                    argv.as_ptr(),
+                    data_cb,
+                    done_cb,
+                ),
+            }
+        };
+        error_ref_mut![
+            subproc,
+            &format!("NcSubprocBuilder.build({:?}, {:?})", self.bin, self.args)
+        ]
+    }
+}
+
+/// # Methods (chainable)
+impl NcSubprocBuilder {
+    /// Appends an argument.
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Appends several arguments.
+    pub fn args(mut self, args: &[&str]) -> Self {
+        self.args.extend(args.iter().map(|a| a.to_string()));
+        self
+    }
+
+    /// Adds an environment variable.
+    ///
+    /// Once any variable is set, the command does **not** inherit the
+    /// environment anymore, and the binary is searched for in `$PATH`.
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env
+            .get_or_insert_with(Vec::new)
+            .push(format!("{}={}", key, value));
+        self
+    }
+
+    /// Whether to search for the binary in `$PATH`.
+    ///
+    /// Default: *false*.
+    pub fn path(mut self, path: bool) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Sets the options.
+    ///
+    /// Default: *no curry, no restart period, no flags*.
+    pub fn options(mut self, options: NcSubprocOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
@@ -1,10 +1,80 @@
-//! `NcVisual*` methods and associated functions.
+//! `NcFdPlane` & `NcSubproc` methods and associated functions.
 
 use core::ffi::c_void;
 
-use crate::{NcSubproc, NcSubprocOptions};
+use crate::{
+    c_api, error, error_ref_mut, NcFd, NcFdPlane, NcFdPlaneCb, NcFdPlaneDoneCb, NcFdPlaneOptions,
+    NcPlane, NcResult, NcSubproc, NcSubprocBuilder, NcSubprocOptions,
+};
 
-/// # NcVisualOptions Constructors
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// # NcFdPlaneOptions Constructors
+impl NcFdPlaneOptions {
+    /// New `NcFdPlaneOptions`.
+    ///
+    /// - `curry` is passed to both callbacks.
+    /// - `follow` keeps reading after hitting EOF (think `tail -f`).
+    pub fn new(curry: *mut c_void, follow: bool, flags: u64) -> Self {
+        Self { curry, follow, flags }
+    }
+}
+
+/// # NcFdPlane Constructors & Destructors
+impl NcFdPlane {
+    /// New `NcFdPlane` that reads from `fd` until EOF, invoking `data_cb`
+    /// with the data read, and `done_cb` on EOF or error.
+    ///
+    /// The reading takes place in a notcurses-managed context.
+    ///
+    /// The callbacks are expected to write the data into the
+    /// [`plane`][NcFdPlane#method.plane], so scrolling is enabled on `plane`
+    /// in order for the output to never run out of room.
+    ///
+    /// Takes ownership of `fd`, which will be closed on
+    /// [`destroy`][NcFdPlane#method.destroy].
+    ///
+    /// *C style function: [ncfdplane_create()][c_api::ncfdplane_create].*
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcFdPlaneOptions,
+        fd: NcFd,
+        data_cb: NcFdPlaneCb,
+        done_cb: NcFdPlaneDoneCb,
+    ) -> NcResult<&'a mut NcFdPlane> {
+        plane.set_scrolling(true);
+        error_ref_mut![
+            unsafe { c_api::ncfdplane_create(plane, options, fd, data_cb, done_cb) },
+            &format!("NcFdPlane::new(NcPlane, {:?}, {})", options, fd)
+        ]
+    }
+
+    /// Destroys this `NcFdPlane`, closing its file descriptor.
+    ///
+    /// If a callback is being invoked, this blocks until it is done.
+    /// It must be called once and only once.
+    ///
+    /// *C style function: [ncfdplane_destroy()][c_api::ncfdplane_destroy].*
+    pub fn destroy(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncfdplane_destroy(self) },
+            "NcFdPlane.destroy()"
+        ]
+    }
+}
+
+/// # NcFdPlane Methods
+impl NcFdPlane {
+    /// Returns a reference to the `NcPlane` the data is written into.
+    ///
+    /// *C style function: [ncfdplane_plane()][c_api::ncfdplane_plane].*
+    pub fn plane(&mut self) -> &mut NcPlane {
+        unsafe { &mut *c_api::ncfdplane_plane(self) }
+    }
+}
+
+/// # NcSubprocOptions Constructors
 impl NcSubprocOptions {
     ///
     pub fn new(curry: *mut c_void, restart_period: u64, flags: u64) -> Self {
@@ -19,7 +89,33 @@ impl NcSubprocOptions {
 }
 
 /// # NcSubproc Constructors & Destructors
-impl NcSubproc {}
+impl NcSubproc {
+    /// Returns a new [`NcSubprocBuilder`] for spawning `bin`.
+    pub fn builder(bin: &str) -> NcSubprocBuilder {
+        NcSubprocBuilder::new(bin)
+    }
+
+    /// Destroys this `NcSubproc`, sending a SIGKILL to the subprocess
+    /// if it has not exited yet.
+    ///
+    /// If a callback is being invoked, this blocks until it is done.
+    /// It must be called once and only once.
+    ///
+    /// *C style function: [ncsubproc_destroy()][c_api::ncsubproc_destroy].*
+    pub fn destroy(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncsubproc_destroy(self) },
+            "NcSubproc.destroy()"
+        ]
+    }
+}
 
 /// # NcSubproc Methods
-impl NcSubproc {}
+impl NcSubproc {
+    /// Returns a reference to the `NcPlane` the output is written into.
+    ///
+    /// *C style function: [ncsubproc_plane()][c_api::ncsubproc_plane].*
+    pub fn plane(&mut self) -> &mut NcPlane {
+        unsafe { &mut *c_api::ncsubproc_plane(self) }
+    }
+}
@@ -1,16 +1,16 @@
 // functions already exported by bindgen : 8
 // -----------------------------------------
-// (W) wrap: 0
-// (#) test: 0
+// (W) wrap: 8
+// (#) test: 2
 // -----------------------------------------
-//   ncfdplane_create
-//   ncfdplane_destroy
-//   ncfdplane_plane
-//   ncsubproc_createv,
-//   ncsubproc_createvp,
-//   ncsubproc_createvpe,
-//   ncsubproc_destroy,
-//   ncsubproc_plane,
+//W# ncfdplane_create
+//W  ncfdplane_destroy
+//W  ncfdplane_plane
+//W  ncsubproc_createv,
+//W# ncsubproc_createvp,
+//W  ncsubproc_createvpe,
+//W  ncsubproc_destroy,
+//W  ncsubproc_plane,
 
 //! from: <https://notcurses.com/notcurses_fds.3.html>
 //!
@@ -35,8 +35,14 @@
 
 use crate::c_api::ffi;
 
+mod builder;
 mod methods;
 
+#[cfg(test)]
+mod test;
+
+pub use builder::NcSubprocBuilder;
+
 /// A raw file descriptor, as returned by [`Nc.inputready_fd`] and
 /// [`NcDirect.inputready_fd`].
 ///
@@ -54,6 +60,21 @@ pub type NcFdPlane = ffi::ncfdplane;
 /// `type in C: ncfdplane_options (struct)`
 pub type NcFdPlaneOptions = ffi::ncfdplane_options;
 
+/// Callback invoked by an [`NcFdPlane`] each time data is read,
+/// with the read bytes, their length, and the `curry` from the options.
+///
+/// The data is *not* guaranteed to be nul-terminated, and may contain
+/// arbitrary zeroes. Returning non-zero makes the `NcFdPlane` destroy itself.
+///
+/// `type in C: ncfdplane_callback`
+pub type NcFdPlaneCb = ffi::ncfdplane_callback;
+
+/// Callback invoked by an [`NcFdPlane`] on EOF or error,
+/// with the `errno` (0 on EOF), and the `curry` from the options.
+///
+/// `type in C: ncfdplane_done_cb`
+pub type NcFdPlaneDoneCb = ffi::ncfdplane_done_cb;
+
 /// [`NcFdPlane`] wrapper with subprocess management.
 ///
 /// `type in C: ncsubproc (struct)`
@@ -0,0 +1,116 @@
+//! Test `NcFdPlane` & `NcSubproc` methods and associated functions.
+
+use serial_test::serial;
+
+use crate::{
+    c_api::notcurses_init_test, NcFdPlane, NcFdPlaneOptions, NcPlane, NcResult, NcSubproc,
+    NcSubprocOptions,
+};
+use std::{
+    io::Write,
+    os::unix::{io::IntoRawFd, net::UnixStream},
+    sync::atomic::{AtomicBool, Ordering},
+    thread::sleep,
+    time::Duration,
+};
+
+/// Writes the read data into the fdplane's plane.
+unsafe extern "C" fn put_data_cb(
+    fdplane: *mut NcFdPlane,
+    buf: *const core::ffi::c_void,
+    len: usize,
+    _curry: *mut core::ffi::c_void,
+) -> i32 {
+    let data = core::slice::from_raw_parts(buf as *const u8, len);
+    let plane = (*fdplane).plane();
+    match plane.putstr(&String::from_utf8_lossy(data)) {
+        Ok(_) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Flags the `AtomicBool` passed as curry.
+unsafe extern "C" fn done_cb(
+    _fdplane: *mut NcFdPlane,
+    _fderrno: i32,
+    curry: *mut core::ffi::c_void,
+) -> i32 {
+    (*(curry as *const AtomicBool)).store(true, Ordering::SeqCst);
+    0
+}
+
+#[test]
+#[serial]
+fn fdplane_pipe() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let (mut writer, reader) = UnixStream::pair().unwrap();
+    writer.write_all(b"hi there").unwrap();
+    drop(writer);
+
+    let done = AtomicBool::new(false);
+    let options = NcFdPlaneOptions::new(&done as *const _ as *mut _, false, 0);
+    let fdplane = NcFdPlane::new(
+        plane,
+        &options,
+        reader.into_raw_fd(),
+        Some(put_data_cb),
+        Some(done_cb),
+    )?;
+
+    for _ in 0..100 {
+        if done.load(Ordering::SeqCst) {
+            break;
+        }
+        sleep(Duration::from_millis(10));
+    }
+    assert![done.load(Ordering::SeqCst)];
+    assert_eq![
+        "hi there",
+        fdplane
+            .plane()
+            .contents(Some(0), Some(0), Some(1), Some(8))?
+    ];
+
+    fdplane.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn subproc_echo() -> NcResult<()> {
+    if !std::path::Path::new("/bin/sh").exists() {
+        return Ok(());
+    }
+
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let done = AtomicBool::new(false);
+    let options = NcSubprocOptions::new(&done as *const _ as *mut _, 0, 0);
+    let subproc = NcSubproc::builder("echo")
+        .arg("hi")
+        .path(true)
+        .options(options)
+        .build(plane, Some(put_data_cb), Some(done_cb))?;
+
+    for _ in 0..100 {
+        if done.load(Ordering::SeqCst) {
+            break;
+        }
+        sleep(Duration::from_millis(10));
+    }
+    assert![done.load(Ordering::SeqCst)];
+    assert_eq![
+        "hi",
+        subproc
+            .plane()
+            .contents(Some(0), Some(0), Some(1), Some(2))?
+    ];
+
+    subproc.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
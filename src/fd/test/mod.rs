@@ -0,0 +1,5 @@
+//! `NcFdPlane` & `NcSubproc` tests.
+
+// the tests need `std` for creating the file descriptors
+#[cfg(all(test, feature = "std"))]
+mod methods;
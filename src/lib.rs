@@ -120,12 +120,15 @@ pub use align::NcAlign;
 pub use alpha::NcAlpha;
 pub use blitter::NcBlitter;
 pub use capabilities::NcCapabilities;
-pub use cell::NcCell;
+pub use cell::{NcCell, NcDecodedCell};
 pub use channel::{NcChannel, NcChannels};
-pub use direct::{NcDirect, NcDirectFlag};
+pub use direct::{NcDirect, NcDirectFlag, NcDirectGuard};
 pub use error::{NcError, NcResult};
 pub use fade::{NcFadeCb, NcFadeCtx};
-pub use fd::{NcFd, NcFdPlane, NcFdPlaneOptions, NcSubproc, NcSubprocOptions};
+pub use fd::{
+    NcFd, NcFdPlane, NcFdPlaneCb, NcFdPlaneDoneCb, NcFdPlaneOptions, NcSubproc, NcSubprocBuilder,
+    NcSubprocOptions,
+};
 #[cfg(feature = "std")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
 pub use file::NcFile;
@@ -135,7 +138,14 @@ pub use log_level::NcLogLevel;
 pub use notcurses::{Nc, NcFlag, NcOptions, NcOptionsBuilder};
 pub use palette::{NcPalette, NcPaletteIndex};
 pub use pixel::{NcPixel, NcPixelGeometry, NcPixelImpl};
-pub use plane::{NcPlane, NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use plane::NcPlaneWriter;
+pub use plane::{
+    NcBoxEdge, NcBoxGlyphs, NcChannelOrder, NcControlPolicy, NcMergeMode, NcPlane, NcPlaneFlag,
+    NcPlaneOptions, NcPlaneOptionsBuilder, NcRenderTracker, NcRgbaExporter, NcRgbaImage,
+    NcScrollRegion, NcScrollback, NcSprixel, NcTextFlow, NcViewport,
+};
 pub use r#box::NcBoxMask;
 pub use resizecb::NcResizeCb;
 pub use rgb::{NcRgb, NcRgba};
@@ -212,6 +222,7 @@ pub mod c_api {
     // private re-export of helper functions for testing:
     mod helpers {
         #![allow(unused_imports)]
+        pub use crate::direct::helpers::*;
         pub use crate::notcurses::helpers::*;
         pub use crate::plane::helpers::*;
     }
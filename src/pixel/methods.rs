@@ -1,6 +1,6 @@
 use crate::{
     c_api::{self, NcPixel_u32},
-    NcPixel, NcRgb, NcRgba,
+    NcPixel, NcPixelGeometry, NcRgb, NcRgba,
 };
 
 impl NcPixel {
@@ -92,6 +92,51 @@ impl NcPixel {
     }
 }
 
+impl NcPixelGeometry {
+    /// Returns `true` if bitmaps are supported.
+    ///
+    /// This is the case when the maximum displayable bitmap is not empty.
+    pub fn supports_pixels(&self) -> bool {
+        self.max_bitmap_y != 0 && self.max_bitmap_x != 0
+    }
+
+    /// Returns the size in pixels of `rows` * `cols` cells.
+    pub fn cells_to_pixels(&self, rows: u32, cols: u32) -> (u32, u32) {
+        (
+            rows.saturating_mul(self.cell_y),
+            cols.saturating_mul(self.cell_x),
+        )
+    }
+
+    /// Returns the number of cells needed to cover `pixels_y` * `pixels_x`
+    /// pixels, rounding up.
+    ///
+    /// Returns 0 along an axis with a cell dimension of 0.
+    pub fn pixels_to_cells(&self, pixels_y: u32, pixels_x: u32) -> (u32, u32) {
+        let div_ceil = |px: u32, cell: u32| {
+            px.checked_div(cell)
+                .map_or(0, |q| q + (px % cell != 0) as u32)
+        };
+        (
+            div_ceil(pixels_y, self.cell_y),
+            div_ceil(pixels_x, self.cell_x),
+        )
+    }
+
+    /// Returns the number of whole cells covered by the maximum displayable
+    /// bitmap.
+    ///
+    /// Returns 0 along an axis with a cell dimension of 0, or if bitmaps are
+    /// not supported.
+    pub fn max_bitmap_cells(&self) -> (u32, u32) {
+        let div = |px: u32, cell: u32| px.checked_div(cell).unwrap_or(0);
+        (
+            div(self.max_bitmap_y, self.cell_y),
+            div(self.max_bitmap_x, self.cell_x),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +148,24 @@ mod tests {
 
         assert_eq![0x44332211_u32, abgr.into()];
     }
+
+    #[test]
+    fn geometry_conversions() {
+        let geom = NcPixelGeometry {
+            term_y: 480,
+            term_x: 800,
+            cell_y: 20,
+            cell_x: 10,
+            max_bitmap_y: 470,
+            max_bitmap_x: 800,
+        };
+        assert_eq![(60, 50), geom.cells_to_pixels(3, 5)];
+        assert_eq![(3, 5), geom.pixels_to_cells(60, 50)];
+        assert_eq![(4, 6), geom.pixels_to_cells(61, 51)];
+        assert_eq![(23, 80), geom.max_bitmap_cells()];
+
+        let zero = NcPixelGeometry { cell_y: 0, cell_x: 0, ..geom };
+        assert_eq![(0, 0), zero.pixels_to_cells(60, 50)];
+        assert_eq![(0, 0), zero.max_bitmap_cells()];
+    }
 }
@@ -0,0 +1,19 @@
+//! `NcBoxEdge`
+
+/// Indicates which horizontal edge of a box [`NcPlane.set_box_title`]
+/// overlays the title onto.
+///
+/// # Default
+/// *[`NcBoxEdge::Top`]*
+///
+/// [`NcPlane.set_box_title`]: crate::NcPlane#method.set_box_title
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcBoxEdge {
+    /// The top border.
+    #[default]
+    Top,
+
+    /// The bottom border.
+    Bottom,
+}
@@ -0,0 +1,43 @@
+//! `NcBoxGlyphs`
+
+use crate::cell::reimplemented::{NCBOXASCII, NCBOXDOUBLE, NCBOXHEAVY, NCBOXLIGHT, NCBOXROUND};
+
+/// The set of line-drawing characters used by [`NcPlane.box_styled`].
+///
+/// # Default
+/// *[`NcBoxGlyphs::Light`]*
+///
+/// [`NcPlane.box_styled`]: crate::NcPlane#method.box_styled
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcBoxGlyphs {
+    /// ASCII characters (`/\\/-|`).
+    Ascii,
+
+    /// Light line box-drawing characters (`┌┐└┘─│`).
+    #[default]
+    Light,
+
+    /// Heavy line box-drawing characters (`┏┓┗┛━┃`).
+    Heavy,
+
+    /// Double line box-drawing characters (`╔╗╚╝═║`).
+    Double,
+
+    /// Light line box-drawing characters with rounded corners (`╭╮╰╯─│`).
+    Rounded,
+}
+
+impl NcBoxGlyphs {
+    /// Returns the six `EGC`s for the upper-left, upper-right, lower-left
+    /// and lower-right corners, and then the horizontal and vertical lines.
+    pub const fn egcs(&self) -> &'static str {
+        match self {
+            NcBoxGlyphs::Ascii => NCBOXASCII,
+            NcBoxGlyphs::Light => NCBOXLIGHT,
+            NcBoxGlyphs::Heavy => NCBOXHEAVY,
+            NcBoxGlyphs::Double => NCBOXDOUBLE,
+            NcBoxGlyphs::Rounded => NCBOXROUND,
+        }
+    }
+}
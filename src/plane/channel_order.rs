@@ -0,0 +1,33 @@
+//! `NcChannelOrder`
+
+/// The byte order of the color components of each pixel exported by
+/// [`NcPlane.to_rgba_bytes`].
+///
+/// # Default
+/// *[`NcChannelOrder::Rgba`]*
+///
+/// [`NcPlane.to_rgba_bytes`]: crate::NcPlane#method.to_rgba_bytes
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcChannelOrder {
+    /// Red, green, blue, alpha.
+    #[default]
+    Rgba,
+
+    /// Blue, green, red, alpha.
+    Bgra,
+
+    /// Alpha, red, green, blue.
+    Argb,
+}
+
+impl NcChannelOrder {
+    /// Reorders the `[r, g, b, a]` components of a pixel.
+    pub const fn reorder(&self, [r, g, b, a]: [u8; 4]) -> [u8; 4] {
+        match self {
+            NcChannelOrder::Rgba => [r, g, b, a],
+            NcChannelOrder::Bgra => [b, g, r, a],
+            NcChannelOrder::Argb => [a, r, g, b],
+        }
+    }
+}
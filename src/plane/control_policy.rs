@@ -0,0 +1,29 @@
+//! `NcControlPolicy`
+
+/// Indicates how [`NcPlane.putstr_normalized`] handles the C0 control
+/// characters (other than the newline) found in a string.
+///
+/// # Default
+/// *[`NcControlPolicy::Skip`]*
+///
+/// [`NcPlane.putstr_normalized`]: crate::NcPlane#method.putstr_normalized
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcControlPolicy {
+    /// Control characters are removed.
+    #[default]
+    Skip,
+
+    /// Control characters are rendered as their Unicode *Control Pictures*
+    /// symbol (e.g. `\r` as `␍`).
+    Symbol,
+
+    /// Control characters are interpreted:
+    /// - `\t` advances the cursor to the next tab stop (every 8 columns),
+    ///   filling the gap with spaces, which is also what is read back
+    ///   from the plane afterwards.
+    /// - `\r` moves the cursor to the beginning of the current line.
+    /// - `\x0c` (*form feed*) erases the plane and homes the cursor.
+    /// - The rest of them are removed.
+    Expand,
+}
@@ -1,4 +1,7 @@
-use crate::{c_api, Nc, NcPlane, NcPlaneOptions};
+use crate::{c_api, cstring, Nc, NcPlane, NcPlaneOptions, NcTime};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
 
 /// Helper function for a new NcPlane on C style tests.
 #[allow(dead_code)]
@@ -23,3 +26,126 @@ pub(crate) unsafe fn ncplane_new_bound_test<'a>(
 ) -> &'a mut NcPlane {
     &mut *c_api::ncplane_create(plane, &NcPlaneOptions::new(y, x, rows, cols))
 }
+
+/// Returns the number of columns occupied by `s`.
+pub(crate) fn str_width(s: &str) -> u32 {
+    let (mut validbytes, mut validwidth) = (0, 0);
+    let cs = cstring![s];
+    unsafe { c_api::ncstrwidth(cs.as_ptr(), &mut validbytes, &mut validwidth) };
+    validwidth.max(0) as u32
+}
+
+/// Splits `text` into its glyphs along with their widths, keeping each
+/// zero-width `char` together with the preceding glyph.
+pub(crate) fn split_glyphs(text: &str) -> Vec<(&str, u32)> {
+    let mut glyphs: Vec<(&str, u32)> = Vec::new();
+    let mut buf = [0; 4];
+    for (i, ch) in text.char_indices() {
+        let width = str_width(ch.encode_utf8(&mut buf));
+        match glyphs.last_mut() {
+            Some((glyph, _)) if width == 0 => {
+                *glyph = &text[i - glyph.len()..i + ch.len_utf8()];
+            }
+            _ => glyphs.push((&text[i..i + ch.len_utf8()], width)),
+        }
+    }
+    glyphs
+}
+
+/// Truncates `text` to at most `width` columns, returning the truncated
+/// text along with its width.
+pub(crate) fn truncate_to_width(text: &str, width: u32) -> (&str, u32) {
+    let mut buf = [0; 4];
+    let mut cols = 0;
+    for (i, ch) in text.char_indices() {
+        let ch_width = str_width(ch.encode_utf8(&mut buf));
+        if cols + ch_width > width {
+            return (&text[..i], cols);
+        }
+        cols += ch_width;
+    }
+    (text, cols)
+}
+
+/// Wraps `text` in rows of at most `width` columns.
+///
+/// Words are broken at spaces, and newlines force a break. Words wider
+/// than `width` are broken across rows, without splitting wide glyphs.
+pub(crate) fn wrap_text(text: &str, width: u32) -> Vec<String> {
+    wrap_text_tracked(text, width)
+        .into_iter()
+        .map(|row| row.text)
+        .collect()
+}
+
+/// A row of wrapped text, returned by [`wrap_text_tracked`].
+pub(crate) struct WrappedRow {
+    /// The byte offset in the source text where the row begins.
+    pub offset: usize,
+    /// Whether the row begins at a soft break, inserted by the wrapping.
+    pub soft: bool,
+    pub text: String,
+}
+
+/// Like [`wrap_text`], but also tracks where each row begins in `text`.
+pub(crate) fn wrap_text_tracked(text: &str, width: u32) -> Vec<WrappedRow> {
+    let mut rows = Vec::new();
+    if width == 0 {
+        return rows;
+    }
+    let mut line_offset = 0;
+    for line in text.lines() {
+        let mut row = WrappedRow { offset: line_offset, soft: false, text: String::new() };
+        let mut col = 0;
+        let mut word_offset = line_offset;
+        for word in line.split(' ') {
+            let offset = word_offset;
+            word_offset += word.len() + 1;
+            if word.is_empty() {
+                continue;
+            }
+            let word_width = str_width(word);
+            if col > 0 && col + 1 + word_width <= width {
+                row.text.push(' ');
+                row.text.push_str(word);
+                col += 1 + word_width;
+                continue;
+            }
+            if col > 0 {
+                let next = WrappedRow { offset, soft: true, text: String::new() };
+                rows.push(core::mem::replace(&mut row, next));
+                col = 0;
+            }
+            if word_width <= width {
+                row.text.push_str(word);
+                col = word_width;
+            } else {
+                // breaks the word, glyph by glyph
+                let mut buf = [0; 4];
+                for (i, ch) in word.char_indices() {
+                    let ch_width = str_width(ch.encode_utf8(&mut buf));
+                    if col + ch_width > width {
+                        let next =
+                            WrappedRow { offset: offset + i, soft: true, text: String::new() };
+                        rows.push(core::mem::replace(&mut row, next));
+                        col = 0;
+                    }
+                    row.text.push(ch);
+                    col += ch_width;
+                }
+            }
+        }
+        rows.push(row);
+        // skips the line terminator, which may be "\r\n"
+        line_offset += line.len();
+        line_offset += if text[line_offset..].starts_with("\r\n") { 2 } else { 1 };
+    }
+    rows
+}
+
+/// Blocks the current thread for the given `time`.
+#[cfg(feature = "libc")]
+pub(crate) fn sleep(time: &NcTime) {
+    let ts = libc::timespec { tv_sec: time.tv_sec as _, tv_nsec: time.tv_nsec as _ };
+    unsafe { libc::nanosleep(&ts, core::ptr::null_mut()) };
+}
@@ -0,0 +1,24 @@
+//! `NcMergeMode`
+
+/// Indicates how [`NcPlane.mergedown_mode`] treats the transparent cells
+/// of the source plane.
+///
+/// # Default
+/// *[`NcMergeMode::Blend`]*
+///
+/// [`NcPlane.mergedown_mode`]: crate::NcPlane#method.mergedown_mode
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcMergeMode {
+    /// The destination region is erased before merging, so its previous
+    /// contents never show through the source.
+    ///
+    /// The alpha of the source is still applied, but over the erased cells,
+    /// so wherever the source is transparent the result is an empty cell.
+    Replace,
+
+    /// The destination shows through the transparent cells of the source,
+    /// following the usual alpha semantics (*composite translucent*).
+    #[default]
+    Blend,
+}
@@ -3,20 +3,32 @@
 use core::{
     ffi::{c_char, c_void},
     ptr::null_mut,
-    slice::from_raw_parts_mut,
+    slice::{from_raw_parts, from_raw_parts_mut},
 };
 
 use crate::{
     c_api, cstring, error, error_ref, error_ref_mut, rstring_free, Nc, NcAlign, NcAlpha, NcBlitter,
-    NcBoxMask, NcCell, NcChannel, NcChannels, NcError, NcFadeCb, NcPaletteIndex, NcPixelGeometry,
-    NcPlane, NcPlaneOptions, NcResizeCb, NcResult, NcRgb, NcRgba, NcStyle, NcTime,
+    NcBoxEdge, NcBoxGlyphs, NcBoxMask, NcCell, NcChannel, NcChannelOrder, NcChannels,
+    NcControlPolicy, NcDecodedCell, NcError, NcFadeCb, NcMergeMode, NcPaletteIndex,
+    NcPixelGeometry, NcPlane, NcPlaneOptions, NcRenderTracker, NcResizeCb, NcResult, NcRgb, NcRgba,
+    NcRgbaExporter, NcRgbaImage, NcStyle, NcTextFlow, NcTime, NcVisualOptions,
 };
 
+#[cfg(feature = "libc")]
+use super::helpers::sleep;
+use super::helpers::{split_glyphs, str_width, truncate_to_width, wrap_text, wrap_text_tracked};
+use crate::resizecb::{ncresize_closure_trampoline, NcResizeClosure};
+
 #[cfg(feature = "std")]
 use crate::NcFile;
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 /// # NcPlane constructors & destructors
 impl NcPlane {
@@ -78,6 +90,19 @@ impl NcPlane {
         Self::new_child(parent, &NcPlaneOptions::new(y, x, rows, cols))
     }
 
+    /// Same as [`new_child_sized`] but the new plane is bound to `bound_to`
+    /// and covers it whole, being placed at its origin and with its same size.
+    ///
+    /// This is useful for overlays over a widget.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`new_child_sized`]: NcPlane#method.new_child_sized
+    pub fn with_parentsize_bound<'plane>(bound_to: &mut NcPlane) -> NcResult<&'plane mut NcPlane> {
+        let (rows, cols) = bound_to.dim_yx();
+        Self::new_child_sized(bound_to, 0, 0, rows, cols)
+    }
+
     /// Same as [`new_child_sized`] but creates a new pile.
     ///
     /// The returned `NcPlane` will be the top, bottom, and root of the new pile.
@@ -96,14 +121,53 @@ impl NcPlane {
         Self::new_pile(nc, &NcPlaneOptions::new(y, x, rows, cols))
     }
 
+    /// Same as [`new_pile_sized`] but the plane covers the terminal, inset by
+    /// the `top`, `right`, `bottom` and `left` margins.
+    ///
+    /// This leaves room around the plane, e.g. for a status bar.
+    ///
+    /// # Errors
+    /// If the margins leave no room for the plane in the current terminal size.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`new_pile_sized`]: NcPlane#method.new_pile_sized
+    pub fn with_termsize_margins<'plane>(
+        nc: &mut Nc,
+        top: u32,
+        right: u32,
+        bottom: u32,
+        left: u32,
+    ) -> NcResult<&'plane mut NcPlane> {
+        let (term_y, term_x) = nc.term_dim_yx();
+        let rows = term_y
+            .checked_sub(top)
+            .and_then(|r| r.checked_sub(bottom))
+            .unwrap_or(0);
+        let cols = term_x
+            .checked_sub(left)
+            .and_then(|c| c.checked_sub(right))
+            .unwrap_or(0);
+        if rows == 0 || cols == 0 {
+            return Err(NcError::new_msg(&format![
+                "NcPlane::with_termsize_margins({}, {}, {}, {}): no room in a {}x{} terminal",
+                top, right, bottom, left, term_y, term_x
+            ]));
+        }
+        Self::new_pile_sized(nc, top as i32, left as i32, rows, cols)
+    }
+
     /// Destroys this `NcPlane`.
     ///
     /// None of its contents will be visible after the next render call.
     ///
     /// It is an error to attempt to destroy the standard plane.
     ///
+    /// Any closure set with [`on_resize`][NcPlane#method.on_resize] is dropped.
+    ///
     /// *C style function: [ncplane_destroy()][c_api::ncplane_destroy].*
     pub fn destroy(&mut self) -> NcResult<()> {
+        self.clear_on_resize();
         error![unsafe { c_api::ncplane_destroy(self) }, "NcPlane.destroy()"]
     }
 }
@@ -167,6 +231,18 @@ impl NcPlane {
         c_api::ncplane_set_channels(self, channels.into().0);
     }
 
+    /// Sets the current [`NcChannels`] for this `NcPlane`,
+    /// returning the previous ones.
+    ///
+    /// This allows to restore the color state after a drawing block.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn replace_channels(&mut self, channels: impl Into<NcChannels>) -> NcChannels {
+        let prior = self.channels();
+        self.set_channels(channels);
+        prior
+    }
+
     /// Gets the foreground [`NcChannel`] from an [`NcPlane`].
     ///
     /// *C style function: [ncplane_fchannel()][c_api::ncplane_fchannel].*
@@ -247,6 +323,125 @@ impl NcPlane {
             res as u32
         ]
     }
+
+    /// Sets the same [`NcChannels`] throughout the specified region,
+    /// keeping content and attributes unchanged.
+    ///
+    /// The region arguments behave the same as in [`stain`][NcPlane#method.stain].
+    ///
+    /// Returns the number of cells set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn stain_uniform(
+        &mut self,
+        y: Option<u32>,
+        x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<u32> {
+        let channels = channels.into();
+        self.stain(y, x, len_y, len_x, channels, channels, channels, channels)
+    }
+
+    /// Briefly highlights the cell at `y`, `x` with the `channels`, keeping
+    /// its content.
+    ///
+    /// The cell is stained, then `render` is called, and after waiting for
+    /// `duration` its original channels are restored and `render` is called
+    /// again. The cell is restored even if the first `render` fails.
+    ///
+    /// This is useful for input feedback, like flashing a pressed key.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn flash_cell(
+        &mut self,
+        y: u32,
+        x: u32,
+        channels: impl Into<NcChannels>,
+        duration: NcTime,
+        mut render: impl FnMut(&mut NcPlane) -> NcResult<()>,
+    ) -> NcResult<()> {
+        let mut cell = NcCell::new();
+        self.at_yx_cell(y, x, &mut cell)?;
+        cell.release(self);
+        let original = cell.channels();
+
+        self.stain_uniform(Some(y), Some(x), Some(1), Some(1), channels)?;
+        let res = render(self);
+        if res.is_ok() {
+            sleep(&duration);
+        }
+        self.stain_uniform(Some(y), Some(x), Some(1), Some(1), original)?;
+        res?;
+        render(self)
+    }
+
+    /// Blends the `overlay` channels over the current channels of the cell at
+    /// `y`, `x`, and stains the cell with the result, keeping its content.
+    ///
+    /// The foreground and background are blended separately, according to
+    /// `alpha`:
+    /// - [`Opaque`][NcAlpha::Opaque] replaces them with the overlay.
+    /// - [`Blend`][NcAlpha::Blend] averages the overlay with them.
+    /// - [`Transparent`][NcAlpha::Transparent] leaves them unchanged.
+    ///
+    /// Channels using the default color are blended as black.
+    ///
+    /// # Errors
+    /// If `alpha` is [`HighContrast`][NcAlpha::HighContrast], or the
+    /// coordinates are outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blend_channels_over(
+        &mut self,
+        y: u32,
+        x: u32,
+        overlay: impl Into<NcChannels>,
+        alpha: impl Into<NcAlpha>,
+    ) -> NcResult<()> {
+        let (overlay, alpha) = (overlay.into(), alpha.into());
+
+        fn blend(under: NcChannel, over: NcChannel) -> NcChannel {
+            let avg = |a: u8, b: u8| ((a as u16 + b as u16) / 2) as u8;
+            let (ur, ug, ub) = under.rgb8();
+            let (or, og, ob) = over.rgb8();
+            NcChannel::from_rgb(NcRgb::new(avg(ur, or), avg(ug, og), avg(ub, ob)))
+        }
+
+        let mut cell = NcCell::new();
+        self.at_yx_cell(y, x, &mut cell)?;
+        cell.release(self);
+        let under = cell.channels();
+
+        let blended = match alpha {
+            NcAlpha::Opaque => overlay,
+            NcAlpha::Transparent => under,
+            NcAlpha::Blend => NcChannels::combine(
+                blend(under.fchannel(), overlay.fchannel()),
+                blend(under.bchannel(), overlay.bchannel()),
+            ),
+            NcAlpha::HighContrast => {
+                return Err(NcError::new_msg(&format!(
+                    "NcPlane.blend_channels_over({}, {}, {:0X}, {}): unsupported alpha",
+                    y, x, overlay, alpha
+                )))
+            }
+        };
+        self.stain(
+            Some(y),
+            Some(x),
+            Some(1),
+            Some(1),
+            blended,
+            blended,
+            blended,
+            blended,
+        )?;
+        Ok(())
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -268,8 +463,39 @@ impl NcPlane {
         c_api::ncchannels_bg_rgb(c_api::ncplane_channels(self)).into()
     }
 
+    /// Gets the foreground [`NcRgb`] from this `NcPlane`, or `None` if it's
+    /// using the "default color", in which case its RGB value is meaningless.
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn fg_color(&self) -> Option<NcRgb> {
+        let channels = self.channels();
+        if channels.fg_default_p() {
+            None
+        } else {
+            Some(channels.fg_rgb())
+        }
+    }
+
+    /// Gets the background [`NcRgb`] from this `NcPlane`, or `None` if it's
+    /// using the "default color", in which case its RGB value is meaningless.
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn bg_color(&self) -> Option<NcRgb> {
+        let channels = self.channels();
+        if channels.bg_default_p() {
+            None
+        } else {
+            Some(channels.bg_rgb())
+        }
+    }
+
     /// Sets the foreground [`NcRgb`] for this `NcPlane`.
     ///
+    /// Accepts anything convertible into an `NcRgb`, like a packed `0xRRGGBB`
+    /// `u32`, an `[u8; 3]` array or an `(u8, u8, u8)` tuple.
+    ///
     /// *C style function: [ncplane_set_fg_rgb()][c_api::ncplane_set_fg_rgb].*
     #[inline]
     pub fn set_fg_rgb(&mut self, rgb: impl Into<NcRgb>) {
@@ -280,6 +506,9 @@ impl NcPlane {
 
     /// Sets the background [`NcRgb`] for this `NcPlane`.
     ///
+    /// Accepts anything convertible into an `NcRgb`, like a packed `0xRRGGBB`
+    /// `u32`, an `[u8; 3]` array or an `(u8, u8, u8)` tuple.
+    ///
     /// *C style function: [ncplane_set_bg_rgb()][c_api::ncplane_set_bg_rgb].*
     #[inline]
     pub fn set_bg_rgb(&mut self, rgb: impl Into<NcRgb>) {
@@ -288,6 +517,68 @@ impl NcPlane {
         }
     }
 
+    /// Sets the foreground [`NcRgb`] for this `NcPlane` from an `[r, g, b]`
+    /// array.
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn set_fg_rgb8_arr(&mut self, rgb: [u8; 3]) {
+        self.set_fg_rgb(rgb);
+    }
+
+    /// Sets the background [`NcRgb`] for this `NcPlane` from an `[r, g, b]`
+    /// array.
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn set_bg_rgb8_arr(&mut self, rgb: [u8; 3]) {
+        self.set_bg_rgb(rgb);
+    }
+
+    /// Sets the foreground [`NcRgb`] for this `NcPlane` to a gray `level`,
+    /// from black (`0`) to white (`255`).
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn set_fg_gray(&mut self, level: u8) {
+        self.set_fg_rgb([level; 3]);
+    }
+
+    /// Sets the background [`NcRgb`] for this `NcPlane` to a gray `level`,
+    /// from black (`0`) to white (`255`).
+    ///
+    /// *(No equivalent C style function)*
+    #[inline]
+    pub fn set_bg_gray(&mut self, level: u8) {
+        self.set_bg_rgb([level; 3]);
+    }
+
+    /// Sets the foreground [`NcRgb`] for this `NcPlane` from a web-style
+    /// `"#RRGGBB"` hex string.
+    ///
+    /// # Errors
+    /// If `hex` is not a valid hex color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_fg_hex(&mut self, hex: &str) -> NcResult<()> {
+        let rgb: NcRgb = hex.parse()?;
+        self.set_fg_rgb(rgb);
+        Ok(())
+    }
+
+    /// Sets the background [`NcRgb`] for this `NcPlane` from a web-style
+    /// `"#RRGGBB"` hex string.
+    ///
+    /// # Errors
+    /// If `hex` is not a valid hex color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_bg_hex(&mut self, hex: &str) -> NcResult<()> {
+        let rgb: NcRgb = hex.parse()?;
+        self.set_bg_rgb(rgb);
+        Ok(())
+    }
+
     /// Is this `NcPlane`'s foreground using the "default foreground color"?
     ///
     /// *C style function: [ncplane_fg_default_p()][c_api::ncplane_fg_default_p].*
@@ -574,6 +865,21 @@ impl NcPlane {
         ]
     }
 
+    /// Returns an owned snapshot of the [`NcCell`] at the specified coordinates.
+    ///
+    /// Unlike [`at_yx_cell`][NcPlane#method.at_yx_cell], the returned
+    /// [`NcDecodedCell`] doesn't depend on this plane, and remains valid after
+    /// it gets destroyed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn read_cell(&mut self, y: u32, x: u32) -> NcResult<NcDecodedCell> {
+        let mut cell = NcCell::new();
+        self.at_yx_cell(y, x, &mut cell)?;
+        let decoded = NcDecodedCell::from_cell(self, &cell);
+        cell.release(self);
+        Ok(decoded)
+    }
+
     /// Extracts this `NcPlane`'s base [`NcCell`].
     ///
     /// The reference is invalidated if this `NcPlane` is destroyed.
@@ -618,6 +924,65 @@ impl NcPlane {
         ]
     }
 
+    /// Sets this `NcPlane`'s base [`NcCell`] to a space, with no style and
+    /// the default channels.
+    ///
+    /// This is the most common background fill.
+    ///
+    /// *C style function: [ncplane_set_base()][c_api::ncplane_set_base].*
+    pub fn set_base_default(&mut self) -> NcResult<u32> {
+        self.set_base(" ", NcStyle::None, NcChannels::with_default())
+    }
+
+    /// Sets this `NcPlane`'s base [`NcCell`] to the `ch` character, with no
+    /// style and the provided `channels`.
+    ///
+    /// *C style function: [ncplane_set_base()][c_api::ncplane_set_base].*
+    pub fn set_base_char(&mut self, ch: char, channels: impl Into<NcChannels>) -> NcResult<u32> {
+        self.set_base(ch.encode_utf8(&mut [0; 4]), NcStyle::None, channels)
+    }
+
+    /// Sets the base cell of this `NcPlane` and of all its bound descendants,
+    /// like [`set_base`][NcPlane#method.set_base].
+    ///
+    /// This allows theming a whole hierarchy of planes at once.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_base_recursive(
+        &mut self,
+        egc: &str,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<()> {
+        let (style, channels) = (style.into(), channels.into());
+        let root: *const NcPlane = self;
+
+        // the descendants are the planes of the pile having this one as ancestor
+        let mut descendants = Vec::new();
+        let mut ptr = unsafe { c_api::ncpile_top(self) };
+        while !ptr.is_null() {
+            let mut ancestor = ptr as *const NcPlane;
+            while ancestor != root {
+                let parent = unsafe { c_api::ncplane_parent_const(ancestor) };
+                if parent == root {
+                    descendants.push(ptr);
+                    break;
+                }
+                if parent.is_null() || parent == ancestor {
+                    break;
+                }
+                ancestor = parent;
+            }
+            ptr = unsafe { c_api::ncplane_below(ptr) };
+        }
+
+        self.set_base(egc, style, channels)?;
+        for plane in descendants {
+            unsafe { &mut *plane }.set_base(egc, style, channels)?;
+        }
+        Ok(())
+    }
+
     /// Sets this `NcPlane`'s base [`NcCell`].
     ///
     /// It will be used for purposes of rendering anywhere that the `NcPlane`'s
@@ -645,10 +1010,16 @@ impl NcPlane {
     /// Use `None` for either or both of `len_y` and `len_x` in order to
     /// go through the boundary of the plane in that axis (same as `0`).
     ///
+    /// Tabs expanded by [`putstr_normalized`] are read back in their expanded
+    /// form, as the spaces that fill the gap, same as in [`cells`].
+    ///
     /// # Errors
     /// If either `len_y` or `len_x` fall outside the plane's boundaries.
     ///
     /// *C style function: [ncplane_contents()][c_api::ncplane_contents].*
+    ///
+    /// [`putstr_normalized`]: NcPlane#method.putstr_normalized
+    /// [`cells`]: NcPlane#method.cells
     pub fn contents(
         &mut self,
         beg_y: Option<u32>,
@@ -675,6 +1046,347 @@ impl NcPlane {
         }
     }
 
+    /// Returns the text of the selected region as it would be displayed,
+    /// including any planes composited on top of it, e.g. its children.
+    ///
+    /// The planes of the pile are composited here from the top down, reading
+    /// the glyph of the topmost plane that has one at each cell (or its base
+    /// cell), so nothing is rendered nor written to the terminal. Only the
+    /// glyphs are composited, not the styles nor the colors.
+    ///
+    /// The region is `((y, x), (len_y, len_x))`, relative to this plane,
+    /// or the whole plane if `None`. The rows are concatenated like in
+    /// [`contents`][NcPlane#method.contents], skipping the cells without a
+    /// glyph in any plane, and the continuation cells of wide glyphs.
+    ///
+    /// # Errors
+    /// If the region doesn't fit in the plane, or isn't entirely on-screen.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn composited_contents(
+        &mut self,
+        region: Option<((u32, u32), (u32, u32))>,
+    ) -> NcResult<String> {
+        let (rows, cols) = self.dim_yx();
+        let ((y, x), (len_y, len_x)) = region.unwrap_or(((0, 0), (rows, cols)));
+        if y as u64 + len_y as u64 > rows as u64 || x as u64 + len_x as u64 > cols as u64 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.composited_contents({:?}): the region doesn't fit in the plane",
+                region
+            )));
+        }
+        let (term_y, term_x) = unsafe { self.notcurses_const()? }.term_dim_yx();
+        let (abs_y, abs_x) = self.abs_yx();
+        let (top, left) = (abs_y as i64 + y as i64, abs_x as i64 + x as i64);
+        if top < 0
+            || left < 0
+            || top + len_y as i64 > term_y as i64
+            || left + len_x as i64 > term_x as i64
+        {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.composited_contents({:?}): the region is off-screen",
+                region
+            )));
+        }
+
+        // the planes of the pile, from the top down, with their base glyph
+        let mut planes = Vec::new();
+        let mut ptr = unsafe { c_api::ncpile_top(self) };
+        while !ptr.is_null() {
+            let plane = unsafe { &mut *ptr };
+            let mut base = plane.base()?;
+            let base_egc = base.egc(plane).to_string();
+            base.release(plane);
+            planes.push((ptr, plane.abs_yx(), plane.dim_yx(), base_egc));
+            ptr = unsafe { c_api::ncplane_below(plane) };
+        }
+
+        let mut text = String::new();
+        for row in top..top + len_y as i64 {
+            for col in left..left + len_x as i64 {
+                for (ptr, (py, px), (rows, cols), base_egc) in planes.iter() {
+                    let (cy, cx) = (row - *py as i64, col - *px as i64);
+                    if cy < 0 || cx < 0 || cy >= *rows as i64 || cx >= *cols as i64 {
+                        continue;
+                    }
+                    let plane = unsafe { &mut **ptr };
+                    let mut cell = NcCell::new();
+                    plane.at_yx_cell(cy as u32, cx as u32, &mut cell)?;
+                    let (wide_right, egc) = (cell.wide_right_p(), cell.egc(plane));
+                    let egc = if egc.is_empty() { base_egc.as_str() } else { egc };
+                    let found = wide_right || !egc.is_empty();
+                    if !wide_right {
+                        text.push_str(egc);
+                    }
+                    cell.release(plane);
+                    if found {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(text)
+    }
+
+    /// Returns the selected region of the `NcPlane` as a grid of cells,
+    /// row by row, each one with its `EGC`, [`NcStyle`] and [`NcChannels`].
+    ///
+    /// This is useful for taking snapshots of the plane to compare them.
+    /// Note that each cell allocates its own `String`, so the memory used
+    /// is many times that of the plane itself.
+    ///
+    /// The continuation cells of wide glyphs have an empty `EGC`.
+    ///
+    /// The region arguments behave the same as in
+    /// [`contents`][NcPlane#method.contents].
+    ///
+    /// # Errors
+    /// If the region falls outside the plane's boundaries.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn contents_detailed(
+        &mut self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<Vec<Vec<(String, NcStyle, NcChannels)>>> {
+        let ((by, bx), (ly, lx)) =
+            self.resolve_region(beg_y, beg_x, len_y, len_x)
+                .ok_or_else(|| {
+                    NcError::new_msg(&format!(
+                        "NcPlane.contents_detailed({:?}, {:?}, {:?}, {:?}): out of bounds",
+                        beg_y, beg_x, len_y, len_x
+                    ))
+                })?;
+
+        let mut grid = Vec::with_capacity(ly as usize);
+        for y in by..by + ly {
+            let mut row = Vec::with_capacity(lx as usize);
+            for x in bx..bx + lx {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y, x, &mut cell)?;
+                let egc =
+                    if cell.wide_right_p() { String::new() } else { cell.egc(self).to_string() };
+                row.push((egc, cell.styles(), cell.channels()));
+                cell.release(self);
+            }
+            grid.push(row);
+        }
+        Ok(grid)
+    }
+
+    /// Returns the selected region of the `NcPlane` as a grid of `char`s,
+    /// row by row.
+    ///
+    /// Each cell is represented by the first `char` of its `EGC`, so this is
+    /// lossy for multi-codepoint `EGC`s (e.g. combining sequences or emoji
+    /// with modifiers). Empty cells are represented by a space, and the
+    /// continuation cells of wide glyphs by `'\0'`.
+    ///
+    /// The region arguments behave the same as in
+    /// [`contents`][NcPlane#method.contents].
+    ///
+    /// # Errors
+    /// If the region falls outside the plane's boundaries.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_char_grid(
+        &mut self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<Vec<Vec<char>>> {
+        let ((by, bx), (ly, lx)) =
+            self.resolve_region(beg_y, beg_x, len_y, len_x)
+                .ok_or_else(|| {
+                    NcError::new_msg(&format!(
+                        "NcPlane.to_char_grid({:?}, {:?}, {:?}, {:?}): out of bounds",
+                        beg_y, beg_x, len_y, len_x
+                    ))
+                })?;
+
+        let mut grid = Vec::with_capacity(ly as usize);
+        for y in by..by + ly {
+            let mut row = Vec::with_capacity(lx as usize);
+            for x in bx..bx + lx {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y, x, &mut cell)?;
+                if cell.wide_right_p() {
+                    row.push('\0');
+                } else {
+                    row.push(cell.egc(self).chars().next().unwrap_or(' '));
+                }
+                cell.release(self);
+            }
+            grid.push(row);
+        }
+        Ok(grid)
+    }
+
+    /// Resolves a region given like in [`contents`][NcPlane#method.contents],
+    /// returning its `((beg_y, beg_x), (len_y, len_x))`, or `None` if it's
+    /// empty or doesn't fit in the plane.
+    fn resolve_region(
+        &self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> Option<((u32, u32), (u32, u32))> {
+        let (cy, cx) = self.cursor_yx();
+        let (rows, cols) = self.dim_yx();
+        let (by, bx) = (beg_y.unwrap_or(cy), beg_x.unwrap_or(cx));
+        let ly = len_y.filter(|l| *l != 0).unwrap_or(rows.saturating_sub(by));
+        let lx = len_x.filter(|l| *l != 0).unwrap_or(cols.saturating_sub(bx));
+        if by >= rows
+            || bx >= cols
+            || by as u64 + ly as u64 > rows as u64
+            || bx as u64 + lx as u64 > cols as u64
+        {
+            return None;
+        }
+        Some(((by, bx), (ly, lx)))
+    }
+
+    /// Returns an iterator over the cells of this `NcPlane`, row by row,
+    /// yielding their `(y, x)` coordinates and an owned snapshot of their
+    /// contents.
+    ///
+    /// Wide glyphs are reported once, at the coordinates of their leftmost
+    /// column, and their continuation columns are skipped, like a terminal
+    /// would display them.
+    ///
+    /// The cells are yielded as [`NcDecodedCell`]s instead of [`NcCell`]s,
+    /// since these would have to be released against this plane, which is
+    /// borrowed by the iterator.
+    ///
+    /// A cell that can't be read is yielded as an error, instead of being
+    /// skipped, so collecting into an `NcResult<Vec<_>>` stops at the first one.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cells(&mut self) -> impl Iterator<Item = NcResult<(u32, u32, NcDecodedCell)>> + '_ {
+        let (rows, cols) = self.dim_yx();
+        (0..rows)
+            .flat_map(move |y| (0..cols).map(move |x| (y, x)))
+            .filter_map(move |(y, x)| {
+                let mut cell = NcCell::new();
+                if let Err(e) = self.at_yx_cell(y, x, &mut cell) {
+                    return Some(Err(e));
+                }
+                let item = if cell.wide_right_p() {
+                    None
+                } else {
+                    Some(Ok((y, x, NcDecodedCell::from_cell(self, &cell))))
+                };
+                cell.release(self);
+                item
+            })
+    }
+
+    /// Returns an iterator over the occupied cells of this `NcPlane`, row by
+    /// row, yielding their `(y, x)` coordinates and their `EGC`.
+    ///
+    /// Empty cells and spaces are skipped, as well as the continuation
+    /// columns of wide glyphs, like in [`cells`][NcPlane#method.cells],
+    /// while the cells that can't be read are yielded as errors.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn occupied_cells(&mut self) -> impl Iterator<Item = NcResult<(u32, u32, String)>> + '_ {
+        self.cells().filter_map(|item| match item {
+            Ok((y, x, cell)) if !cell.egc.is_empty() && cell.egc != " " => {
+                Some(Ok((y, x, cell.egc)))
+            }
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+    }
+
+    /// Returns an iterator over the rows of this `NcPlane`, yielding the
+    /// [`contents`][NcPlane#method.contents] of each one.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn rows_contents(&mut self) -> impl Iterator<Item = String> + '_ {
+        let (rows, cols) = self.dim_yx();
+        (0..rows).filter_map(move |y| self.contents(Some(y), Some(0), Some(1), Some(cols)).ok())
+    }
+
+    /// Searches for `needle` in the contents of this `NcPlane`, row by row,
+    /// returning the starting `(y, x)` coordinates of every match.
+    ///
+    /// With `region` as `Some(((y, x), (len_y, len_x)))` only that rectangle
+    /// is searched, otherwise the whole plane is.
+    ///
+    /// Matches don't wrap across rows, and empty cells are matched as spaces.
+    ///
+    /// # Errors
+    /// If the region doesn't fit inside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn find(
+        &mut self,
+        needle: &str,
+        region: Option<((u32, u32), (u32, u32))>,
+    ) -> NcResult<Vec<(u32, u32)>> {
+        let (rows, cols) = self.dim_yx();
+        let ((by, bx), (ly, lx)) = region.unwrap_or(((0, 0), (rows, cols)));
+        if by as u64 + ly as u64 > rows as u64 || bx as u64 + lx as u64 > cols as u64 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.find({:?}, {:?}): the region doesn't fit in the plane",
+                needle, region
+            )));
+        }
+
+        let mut found = Vec::new();
+        if needle.is_empty() {
+            return Ok(found);
+        }
+        for y in by..by + ly {
+            // the row contents, and the column at each byte offset
+            let mut row = String::new();
+            let mut columns = Vec::new();
+            for x in bx..bx + lx {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y, x, &mut cell)?;
+                if !cell.wide_right_p() {
+                    let egc = cell.egc(self);
+                    let egc = if egc.is_empty() { " " } else { egc };
+                    row.push_str(egc);
+                    columns.extend(core::iter::repeat(x).take(egc.len()));
+                }
+                cell.release(self);
+            }
+            found.extend(row.match_indices(needle).map(|(i, _)| (y, columns[i])));
+        }
+        Ok(found)
+    }
+
+    /// Searches for `needle` like [`find`][NcPlane#method.find], and stains
+    /// the cells of every match with the `channels`, keeping their glyphs.
+    ///
+    /// Returns the number of matches.
+    ///
+    /// # Errors
+    /// If the region doesn't fit inside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn highlight(
+        &mut self,
+        needle: &str,
+        channels: impl Into<NcChannels>,
+        region: Option<((u32, u32), (u32, u32))>,
+    ) -> NcResult<usize> {
+        let channels = channels.into();
+        let (width, cols) = (str_width(needle), self.dim_x());
+        let found = self.find(needle, region)?;
+        for &(y, x) in &found {
+            let len_x = width.min(cols - x);
+            self.stain_uniform(Some(y), Some(x), Some(1), Some(len_x), channels)?;
+        }
+        Ok(found.len())
+    }
+
     /// Erases every [`NcCell`] in this `NcPlane`, resetting all attributes to
     /// normal, all colors to the default color, and all cells to undrawn.
     ///
@@ -747,6 +1459,48 @@ impl NcPlane {
         ]
     }
 
+    /// Clears a rectangular region of this `NcPlane`, or the whole plane.
+    ///
+    /// With `None` the whole plane is [`erase`][NcPlane#method.erase]d.
+    /// With `Some(((y, x), (len_y, len_x)))` only the rectangle starting at
+    /// `y` * `x` and spanning `len_y` * `len_x` cells is erased, leaving
+    /// the cursor untouched.
+    ///
+    /// This is a simpler alternative to [`erase_region`], with unsigned
+    /// lengths always extending down and to the right.
+    ///
+    /// Returns the number of cells cleared.
+    ///
+    /// # Errors
+    /// If the rectangle doesn't fit inside the plane.
+    ///
+    /// [`erase_region`]: NcPlane#method.erase_region
+    ///
+    /// *(No equivalent C style function)*
+    pub fn clear(&mut self, region: Option<((u32, u32), (u32, u32))>) -> NcResult<usize> {
+        let (rows, cols) = self.dim_yx();
+        match region {
+            None => {
+                self.erase();
+                Ok(rows as usize * cols as usize)
+            }
+            Some(((y, x), (len_y, len_x))) => {
+                if y as u64 + len_y as u64 > rows as u64 || x as u64 + len_x as u64 > cols as u64 {
+                    return Err(NcError::new_msg(&format!(
+                        "NcPlane.clear({:?}): the region doesn't fit in the plane",
+                        region
+                    )));
+                }
+                // a 0 length would erase everything along that dimension
+                if len_y == 0 || len_x == 0 {
+                    return Ok(0);
+                }
+                self.erase_region(Some(y), Some(x), len_y as i32, len_x as i32)?;
+                Ok(len_y as usize * len_x as usize)
+            }
+        }
+    }
+
     /// Replaces the `NcCell` at the **specified** coordinates with the provided
     /// `NcCell`, advancing the cursor by its width (but not past the end of
     /// the plane).
@@ -768,6 +1522,30 @@ impl NcPlane {
         ]
     }
 
+    /// Like [`putc_yx`][NcPlane#method.putc_yx], but any coordinate set to
+    /// `None` is taken from the current cursor location.
+    ///
+    /// This allows fixing only one axis, like [`putstr_yx`] does.
+    ///
+    /// [`putstr_yx`]: NcPlane#method.putstr_yx
+    ///
+    /// *C style function: [ncplane_putc_yx()][c_api::ncplane_putc_yx].*
+    pub fn putc_yx_opt(&mut self, y: Option<u32>, x: Option<u32>, cell: &NcCell) -> NcResult<u32> {
+        let res = unsafe {
+            c_api::ncplane_putc_yx(
+                self,
+                y.unwrap_or(u32::MAX) as i32,
+                x.unwrap_or(u32::MAX) as i32,
+                cell,
+            )
+        };
+        error![
+            res,
+            &format!("NcPlane.putc_yx_opt({:?}, {:?}, {:?})", y, x, cell),
+            res as u32
+        ]
+    }
+
     /// Replaces the [`NcCell`] at the **current** coordinates with the provided
     /// `NcCell`, advancing the cursor by its width (but not past the end of
     /// the plane).
@@ -785,6 +1563,33 @@ impl NcPlane {
         error![res, &format!("NcPlane.putc({:?})", cell), res as u32]
     }
 
+    /// Primes a temporary [`NcCell`] with `ch` and the current plane's
+    /// styles & channels, and puts it at the current cursor location.
+    ///
+    /// This avoids having to manage the lifetime of the cell when calling
+    /// [`putc`][NcPlane#method.putc] with a `char`.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// If the glyph can not fit in the current line, it is an error, unless
+    /// scrolling is enabled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_char_cell(&mut self, ch: char) -> NcResult<u32> {
+        let mut cell = NcCell::new();
+        let (styles, channels) = (self.styles(), self.channels());
+        NcCell::prime(
+            self,
+            &mut cell,
+            ch.encode_utf8(&mut [0; 4]),
+            styles,
+            channels,
+        )?;
+        let res = self.putc(&cell);
+        cell.release(self);
+        res
+    }
+
     /// Calls [`putchar_yx`][NcPlane#method.putchar_yx] at the current cursor
     /// location.
     ///
@@ -916,6 +1721,85 @@ impl NcPlane {
         ]
     }
 
+    /// Writes `text` to the plane wrapped at its width, beginning at the
+    /// start of the current row, and tracks where the soft breaks occurred.
+    ///
+    /// Words are broken at spaces, and newlines force a break. Words wider
+    /// than the plane are broken across rows.
+    ///
+    /// Returns the number of rows used and the byte offsets in `text` where
+    /// each soft break was inserted, i.e. where each wrapped row begins.
+    /// This allows mapping the rendered rows back to the source text.
+    ///
+    /// If the text doesn't fit in the remaining rows, it is an error,
+    /// unless scrolling is enabled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_wrapped_tracked(&mut self, text: &str) -> NcResult<(u32, Vec<usize>)> {
+        let rows = wrap_text_tracked(text, self.dim_x());
+        let y = self.cursor_y();
+        self.cursor_move_yx(y, 0)?;
+
+        let mut breaks = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                self.putstr("\n")?;
+            }
+            if row.soft {
+                breaks.push(row.offset);
+            }
+            if !row.text.is_empty() {
+                self.putstr(&row.text)?;
+            }
+        }
+        Ok((rows.len() as u32, breaks))
+    }
+
+    /// Writes long `text` to the plane, breaking lines like
+    /// [`puttext`][NcPlane#method.puttext] and scrolling as needed,
+    /// beginning at the current line.
+    ///
+    /// `on_page` is called each time the plane has been filled with new rows,
+    /// before writing the next row, so that the caller can pause, render, or
+    /// clear the plane. The first page is the one that ends at the bottom of
+    /// the plane, counting from the row of the cursor.
+    ///
+    /// Scrolling is enabled while writing, and its previous state restored
+    /// afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn puttext_scrolling(
+        &mut self,
+        align: impl Into<NcAlign>,
+        text: &str,
+        mut on_page: impl FnMut(&mut NcPlane) -> NcResult<()>,
+    ) -> NcResult<()> {
+        let align = align.into();
+        let (page_rows, cols) = self.dim_yx();
+        let was_scrolling = self.set_scrolling(true);
+
+        let mut write = || -> NcResult<()> {
+            let mut rows_on_page = self.cursor_y();
+            for (i, row) in wrap_text(text, cols).iter().enumerate() {
+                if rows_on_page == page_rows {
+                    on_page(self)?;
+                    rows_on_page = 0;
+                }
+                if i > 0 {
+                    self.putstr("\n")?;
+                }
+                if !row.is_empty() {
+                    self.putstr_aligned(None, align, row)?;
+                }
+                rows_on_page += 1;
+            }
+            Ok(())
+        };
+        let res = write();
+        self.set_scrolling(was_scrolling);
+        res
+    }
+
     /// Write the specified text to the plane, breaking lines sensibly,
     /// beginning at the specified line.
     ///
@@ -948,6 +1832,175 @@ impl NcPlane {
         error![res, &format!("NcPlane.puttext({:?})", string), res as u32]
     }
 
+    /// Writes a string to the current location, using the current style,
+    /// returning both the number of columns advanced and of bytes consumed.
+    ///
+    /// If a glyph can not be written, e.g. because it doesn't fit in the
+    /// current line and scrolling is disabled, the write stops there, so the
+    /// rest of the string can be resumed from the returned byte offset.
+    ///
+    /// # Errors
+    /// If not even the first glyph of a non-empty string could be written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_tracked(&mut self, string: &str) -> NcResult<(u32, usize)> {
+        let cs = cstring![string];
+        let (mut cols, mut bytes) = (0, 0);
+        while bytes < string.len() {
+            let mut sbytes = 0;
+            let res = unsafe {
+                c_api::ffi::ncplane_putegc_yx(self, -1, -1, cs.as_ptr().add(bytes), &mut sbytes)
+            };
+            if res < 0 || sbytes == 0 {
+                if bytes == 0 {
+                    return Err(NcError::with_msg(
+                        res,
+                        &format!("NcPlane.putstr_tracked({:?})", string),
+                    ));
+                }
+                break;
+            }
+            cols += res as u32;
+            bytes += sbytes;
+        }
+        Ok((cols, bytes))
+    }
+
+    /// Writes a string right-to-left from the current location, using the
+    /// current style, returning the number of columns written.
+    ///
+    /// The first glyph of the string is put at the cursor, and each
+    /// following one to the left of the previous one, so that text in
+    /// logical order, e.g. Arabic or Hebrew, reads right-to-left.
+    /// The cursor is left at the column where the next glyph would go,
+    /// or at column `0` if the string reached the left edge of the plane.
+    ///
+    /// This only does visual ordering, one glyph at a time. There's no
+    /// shaping, and no bidirectional algorithm. Zero-width `char`s, like
+    /// combining marks, are kept together with the preceding glyph, and
+    /// skipped if there's none.
+    ///
+    /// # Errors
+    /// If the string doesn't fit to the left of the cursor.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_rtl(&mut self, string: &str) -> NcResult<u32> {
+        let (y, x) = self.cursor_yx();
+        let glyphs = split_glyphs(string);
+        let width: u32 = glyphs.iter().map(|(_, width)| width).sum();
+        // nothing is written if it doesn't fit
+        if width > x + 1 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.putstr_rtl({:?}): doesn't fit left of column {}",
+                string, x
+            )));
+        }
+
+        let mut right = x + 1;
+        for (glyph, width) in glyphs {
+            if width == 0 {
+                continue;
+            }
+            right -= width;
+            self.putstr_yx(Some(y), Some(right), glyph)?;
+        }
+        self.cursor_move_yx(y, right.saturating_sub(1))?;
+        Ok(x + 1 - right)
+    }
+
+    /// Writes a string in a single row starting at `y`,`x`, dropping any
+    /// glyph that falls outside of the `clip` region, even if it's inside
+    /// the plane, and returns the number of columns written.
+    ///
+    /// The `clip` region is given as `((y, x), (len_y, len_x))`, relative to
+    /// this plane. A wide glyph partially outside of it is dropped entirely.
+    /// Zero-width `char`s are kept together with the preceding glyph.
+    ///
+    /// This allows drawing scrolled content into a viewport of a larger plane.
+    /// The cursor is left after the last glyph written, if any.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_clipped(
+        &mut self,
+        y: i32,
+        x: i32,
+        string: &str,
+        clip: ((u32, u32), (u32, u32)),
+    ) -> NcResult<u32> {
+        let ((clip_y, clip_x), (clip_len_y, clip_len_x)) = clip;
+        let (rows, cols) = self.dim_yx();
+        let (end_y, end_x) = (
+            (clip_y as i64 + clip_len_y as i64).min(rows as i64),
+            (clip_x as i64 + clip_len_x as i64).min(cols as i64),
+        );
+        let row = y as i64;
+        if row < clip_y as i64 || row >= end_y {
+            return Ok(0);
+        }
+
+        let (mut col, mut written) = (x as i64, 0);
+        for (glyph, width) in split_glyphs(string) {
+            if col >= end_x {
+                break;
+            }
+            if col >= clip_x as i64 && col + width as i64 <= end_x {
+                self.putstr_yx(Some(row as u32), Some(col as u32), glyph)?;
+                written += width;
+            }
+            col += width as i64;
+        }
+        Ok(written)
+    }
+
+    /// Stamps a multiline `block` of text with its upper-left corner at
+    /// `origin`, putting each line in the following row, using the provided
+    /// `style` and `channels`.
+    ///
+    /// The layout of the block is preserved: there's no wrapping nor
+    /// scrolling, and spaces overwrite the previous cells. The parts of the
+    /// block falling outside of the plane are dropped.
+    ///
+    /// Returns the `(len_y, len_x)` size of the area used.
+    /// The styles and channels of the plane are restored afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blit_text_block(
+        &mut self,
+        origin: (u32, u32),
+        block: &str,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<(u32, u32)> {
+        let (rows, cols) = self.dim_yx();
+        let plane_area = ((0, 0), (rows, cols));
+
+        let (prev_styles, prev_channels) = (self.styles(), self.channels());
+        self.set_styles(style);
+        self.set_channels(channels);
+        let mut res = Ok((0, 0));
+        for (i, line) in block.lines().enumerate() {
+            let y = origin.0 as i64 + i as i64;
+            if y >= rows as i64 {
+                break;
+            }
+            match self.putstr_clipped(y as i32, origin.1 as i32, line, plane_area) {
+                Ok(len_x) => {
+                    if let Ok((len_y, max_x)) = &mut res {
+                        *len_y = i as u32 + 1;
+                        *max_x = len_x.max(*max_x);
+                    }
+                }
+                Err(e) => {
+                    res = Err(e);
+                    break;
+                }
+            }
+        }
+        self.set_styles(prev_styles);
+        self.set_channels(prev_channels);
+        res
+    }
+
     /// Writes a string to the current location, using the current style.
     ///
     /// Advances the cursor by some positive number of columns (though not
@@ -968,6 +2021,98 @@ impl NcPlane {
         error![res, &format!("NcPlane.putstr({:?})", string), res as u32]
     }
 
+    /// Writes the `text` of a hyperlink pointing to `url` at the current
+    /// location, using the current style.
+    ///
+    /// Planes are rendered cell by cell, and there's no way to carry the
+    /// OSC 8 escape sequences through their output, so this always degrades
+    /// to writing the `text` as plain text. Use
+    /// [`NcDirect.put_hyperlink`][crate::NcDirect#method.put_hyperlink] for
+    /// actual hyperlinks.
+    ///
+    /// Returns the number of columns advanced, like
+    /// [`putstr`][NcPlane#method.putstr].
+    ///
+    /// # Errors
+    /// If the `text` or the `url` contain control characters.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn put_hyperlink(&mut self, text: &str, url: &str) -> NcResult<u32> {
+        if text.chars().chain(url.chars()).any(char::is_control) {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.put_hyperlink({:?}, {:?}): invalid control characters",
+                text, url
+            )));
+        }
+        let res = c_api::ncplane_putstr(self, text);
+        error![
+            res,
+            &format!("NcPlane.put_hyperlink({:?}, {:?})", text, url),
+            res as u32
+        ]
+    }
+
+    /// Writes a string to the current location, using the current style,
+    /// handling the C0 control characters according to the `policy`.
+    ///
+    /// Newlines are always passed through, while the rest of control
+    /// characters (e.g. `\r`, `\t` or `\x0c`) are handled as specified by
+    /// [`NcControlPolicy`], which avoids garbled output from arbitrary text.
+    ///
+    /// Returns the number of columns the cursor advanced on success. With
+    /// [`NcControlPolicy::Expand`] that's the net advance: a carriage return
+    /// discounts the columns it moves back (without going below `0`),
+    /// and a form feed starts counting again from the origin.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_normalized(&mut self, string: &str, policy: NcControlPolicy) -> NcResult<u32> {
+        let is_control = |c: char| c.is_ascii_control() && c != '\n';
+
+        match policy {
+            NcControlPolicy::Skip => {
+                let normalized: String = string.chars().filter(|c| !is_control(*c)).collect();
+                self.putstr(&normalized)
+            }
+            NcControlPolicy::Symbol => {
+                let normalized: String = string
+                    .chars()
+                    .map(|c| match c {
+                        '\x7f' => '\u{2421}',
+                        c if is_control(c) => char::from_u32(0x2400 + c as u32).unwrap_or(c),
+                        c => c,
+                    })
+                    .collect();
+                self.putstr(&normalized)
+            }
+            NcControlPolicy::Expand => {
+                const TAB_WIDTH: u32 = 8;
+                let mut cols = 0;
+                let mut start = 0;
+                for (i, c) in string.char_indices().filter(|(_, c)| is_control(*c)) {
+                    cols += self.putstr(&string[start..i])?;
+                    start = i + c.len_utf8();
+                    match c {
+                        '\t' => {
+                            let spaces = TAB_WIDTH - self.cursor_x() % TAB_WIDTH;
+                            cols += self.putstr(&" ".repeat(spaces as usize))?;
+                        }
+                        '\r' => {
+                            cols = cols.saturating_sub(self.cursor_x());
+                            self.cursor_move_x(0)?;
+                        }
+                        '\x0c' => {
+                            cols = 0;
+                            self.erase();
+                        }
+                        _ => (),
+                    }
+                }
+                cols += self.putstr(&string[start..])?;
+                Ok(cols)
+            }
+        }
+    }
+
     /// Same as [`putstr`][NcPlane#method.putstr], but it also puts a newline
     /// character at the end.
     ///
@@ -990,6 +2135,184 @@ impl NcPlane {
         Ok(cols)
     }
 
+    /// Writes a string from the current location, using the current style,
+    /// wrapped in lines of at most `wrap_cols` columns.
+    ///
+    /// Words are broken at spaces, and newlines force a break. Words wider
+    /// than `wrap_cols` are broken across lines, without splitting wide glyphs.
+    /// Widths are measured in columns, not bytes.
+    ///
+    /// Each line but the last one is followed by a newline, so scrolling
+    /// must be enabled.
+    ///
+    /// Returns the number of lines written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_wrapped(&mut self, string: &str, wrap_cols: u32) -> NcResult<u32> {
+        if !self.scrolling_p() {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.putstr_wrapped({:?}, {}): the plane is not scrolling",
+                string, wrap_cols
+            )));
+        }
+        let lines = wrap_text(string, wrap_cols);
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                self.putln()?;
+            }
+            if !line.is_empty() {
+                self.putstr(line)?;
+            }
+        }
+        Ok(lines.len() as u32)
+    }
+
+    /// Writes a string from the current location, using the current style,
+    /// wrapped within an area of `max` `(len_y, len_x)` cells.
+    ///
+    /// The text is wrapped like in [`putstr_wrapped`], but each line is put
+    /// at the column of the cursor without scrolling. If the text doesn't fit
+    /// in `len_y` lines, the last one is truncated and ended with an ellipsis
+    /// (`…`), which always fits within `len_x`.
+    ///
+    /// Returns the number of lines written.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`putstr_wrapped`]: NcPlane#method.putstr_wrapped
+    pub fn putstr_ellipsized(&mut self, string: &str, max: (u32, u32)) -> NcResult<u32> {
+        let (len_y, len_x) = max;
+        let (y, x) = self.cursor_yx();
+        let mut lines = wrap_text(string, len_x);
+        if lines.len() > len_y as usize {
+            lines.truncate(len_y as usize);
+            if let Some(last) = lines.last_mut() {
+                let (truncated, _) = truncate_to_width(last.trim_end(), len_x - 1);
+                *last = format!("{}\u{2026}", truncated);
+            }
+        }
+        for (i, line) in lines.iter().enumerate() {
+            self.cursor_move_yx(y + i as u32, x)?;
+            if !line.is_empty() {
+                self.putstr(line)?;
+            }
+        }
+        Ok(lines.len() as u32)
+    }
+
+    /// Writes a string to the current location, using the current style,
+    /// calling `on_full` each time the plane fills up, and then resuming.
+    ///
+    /// Glyphs that don't fit in the current line continue at the start of the
+    /// next one, and newlines are honored even if scrolling is disabled.
+    /// When there are no more rows left, `on_full` is called (e.g. to scroll,
+    /// erase, or render the page) and the write is retried.
+    ///
+    /// It is an error if the glyph still can not be written after `on_full`,
+    /// or if `on_full` itself returns an error.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_with_overflow(
+        &mut self,
+        string: &str,
+        mut on_full: impl FnMut(&mut NcPlane) -> NcResult<()>,
+    ) -> NcResult<()> {
+        let cs = cstring![string];
+        let mut cs_ptr = cs.as_ptr();
+
+        while unsafe { cs_ptr.read() != 0 } {
+            let mut retried = false;
+            loop {
+                let is_newline = unsafe { cs_ptr.read() } == b'\n' as c_char;
+                let mut wcs = 0;
+                let cols = if is_newline && !self.scrolling_p() {
+                    wcs = 1;
+                    c_api::NCRESULT_ERR
+                } else {
+                    unsafe { c_api::ffi::ncplane_putegc_yx(self, -1, -1, cs_ptr, &mut wcs) }
+                };
+
+                if cols >= 0 {
+                    if wcs == 0 {
+                        return Ok(());
+                    }
+                    cs_ptr = unsafe { cs_ptr.add(wcs) };
+                    break;
+                }
+                if retried {
+                    return Err(NcError::with_msg(
+                        cols,
+                        &format!("NcPlane.putstr_with_overflow({:?}, …)", string),
+                    ));
+                }
+
+                // continue on the next row, or make room if there's none left
+                let next_row = self.cursor_y() + 1;
+                if next_row < self.dim_y() {
+                    self.cursor_move_yx(next_row, 0)?;
+                } else {
+                    on_full(self)?;
+                }
+                if is_newline {
+                    cs_ptr = unsafe { cs_ptr.add(wcs) };
+                    break;
+                }
+                retried = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a string to the current location, styling each `EGC` with the
+    /// styles & channels returned by `style_fn`.
+    ///
+    /// `style_fn` receives the byte offset of each `EGC` in `string`, and its
+    /// first `char`. This allows e.g. highlighting syntax or search matches.
+    ///
+    /// The plane's prior styles & channels are restored afterwards.
+    ///
+    /// Returns the number of columns the cursor was advanced on success.
+    ///
+    /// If a glyph can not fit in the current line, it is an error, unless
+    /// scrolling is enabled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_styled(
+        &mut self,
+        string: &str,
+        mut style_fn: impl FnMut(usize, char) -> (NcStyle, NcChannels),
+    ) -> NcResult<u32> {
+        let (styles, channels) = (self.styles(), self.channels());
+        let cs = cstring![string];
+        let mut offset = 0;
+        let mut cols = 0;
+
+        let mut res = Ok(());
+        while let Some(ch) = string[offset..].chars().next() {
+            let (style, chans) = style_fn(offset, ch);
+            self.set_styles(style);
+            self.set_channels(chans);
+
+            let mut wcs = 0;
+            let res_cols = unsafe {
+                c_api::ffi::ncplane_putegc_yx(self, -1, -1, cs.as_ptr().add(offset), &mut wcs)
+            };
+            if res_cols < 0 || wcs == 0 {
+                res = Err(NcError::with_msg(
+                    res_cols,
+                    &format!("NcPlane.putstr_styled({:?}, …)", string),
+                ));
+                break;
+            }
+            cols += res_cols as u32;
+            offset += wcs;
+        }
+
+        self.set_styles(styles);
+        self.set_channels(channels);
+        res.map(|_| cols)
+    }
+
     /// Writes a string to the current location, retaining the previous style.
     ///
     /// Advances the cursor by some positive number of columns (though not
@@ -1060,6 +2383,27 @@ impl NcPlane {
         ]
     }
 
+    /// Writes a multi-line string starting at the provided location, using the
+    /// current style, without needing scrolling.
+    ///
+    /// The string is split by lines, each one written at successive rows
+    /// starting at column `x`.
+    ///
+    /// Returns the number of rows used.
+    ///
+    /// It is an error if there are not enough rows left in the plane, or if
+    /// a line can not fit in its row.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_lines(&mut self, y: u32, x: u32, string: &str) -> NcResult<u32> {
+        let mut rows = 0;
+        for line in string.lines() {
+            self.putstr_yx(Some(y + rows), Some(x), line)?;
+            rows += 1;
+        }
+        Ok(rows)
+    }
+
     /// Writes a string to the provided location, [`NcAlign`]ed on *x*
     /// and retaining the previous style.
     ///
@@ -1208,6 +2552,138 @@ impl NcPlane {
         ]
     }
 
+    /// Writes a grid of styled characters with its upper-left corner at
+    /// `y`, `x`, one row per inner slice, one column per character.
+    ///
+    /// The plane's current styles, channels & cursor are left unchanged.
+    ///
+    /// # Errors
+    /// If the grid doesn't fit inside the plane, in which case nothing is
+    /// written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blit_grid(
+        &mut self,
+        y: u32,
+        x: u32,
+        grid: &[&[(char, NcStyle, NcChannels)]],
+    ) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        let grid_cols = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        if y as usize + grid.len() > rows as usize || x as usize + grid_cols > cols as usize {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.blit_grid({}, {}, …): the {}x{} grid doesn't fit in the plane",
+                y,
+                x,
+                grid.len(),
+                grid_cols
+            )));
+        }
+
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let mut buf = [0; 4];
+        for (row_y, row) in (y..).zip(grid.iter()) {
+            for (col_x, (ch, style, channels)) in (x..).zip(row.iter()) {
+                let mut cell = NcCell::new();
+                NcCell::prime(self, &mut cell, ch.encode_utf8(&mut buf), *style, *channels)?;
+                let res = self.putc_yx(row_y, col_x, &cell);
+                cell.release(self);
+                res?;
+            }
+        }
+        self.cursor_move_yx(cursor_y, cursor_x)
+    }
+
+    /// Sets the current `style` and `channels` of this `NcPlane`, and writes
+    /// the `ch` character to every cell of the plane.
+    ///
+    /// Returns the number of characters written. The cursor is homed afterwards.
+    ///
+    /// If `ch` is a wide character and doesn't fit at the end of a row,
+    /// that last column is left unchanged.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fill_char(
+        &mut self,
+        ch: char,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<usize> {
+        self.set_styles(style);
+        self.set_channels(channels);
+
+        let (rows, cols) = self.dim_yx();
+        let width = str_width(ch.encode_utf8(&mut [0; 4])).max(1);
+        let mut count = 0;
+        for y in 0..rows {
+            let mut x = 0;
+            // a wide character that doesn't fit at the end of the row is skipped
+            while x + width <= cols {
+                x += self.putchar_yx(y, x, ch)?.max(1);
+                count += 1;
+            }
+        }
+        self.cursor_home();
+        Ok(count)
+    }
+
+    /// Writes `cell` to every position of the selected rectangle, returning
+    /// the number of cells written.
+    ///
+    /// Use `None` for either or both of `beg_y` and `beg_x` in order to
+    /// use the current cursor position along that axis.
+    ///
+    /// Use `0` for either or both of `len_y` and `len_x` in order to
+    /// go through the boundary of the plane in that axis.
+    ///
+    /// A wide `cell` advances by its width, and if it doesn't fit in the
+    /// last column of the rectangle, that column is left unchanged.
+    ///
+    /// The cell must already be associated with this `NcPlane`.
+    /// The cursor is left untouched.
+    ///
+    /// # Errors
+    /// If the rectangle falls outside the plane's boundaries.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fill(
+        &mut self,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: u32,
+        len_x: u32,
+        cell: &NcCell,
+    ) -> NcResult<usize> {
+        let (cy, cx) = self.cursor_yx();
+        let (rows, cols) = self.dim_yx();
+        let (by, bx) = (beg_y.unwrap_or(cy), beg_x.unwrap_or(cx));
+        let ly = if len_y == 0 { rows.saturating_sub(by) } else { len_y };
+        let lx = if len_x == 0 { cols.saturating_sub(bx) } else { len_x };
+        if by >= rows
+            || bx >= cols
+            || by as u64 + ly as u64 > rows as u64
+            || bx as u64 + lx as u64 > cols as u64
+        {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.fill({:?}, {:?}, {}, {}): out of bounds",
+                beg_y, beg_x, len_y, len_x
+            )));
+        }
+
+        let width = (cell.cols() as u32).max(1);
+        let mut count = 0;
+        for y in by..by + ly {
+            let mut x = bx;
+            while x + width <= bx + lx {
+                self.putc_yx(y, x, cell)?;
+                count += 1;
+                x += width;
+            }
+        }
+        self.cursor_move_yx(cy, cx)?;
+        Ok(count)
+    }
+
     /// Considers the glyph at `y`,`x` coordinates as the fill target,
     /// and copies `cell` to it and to all cardinally-connected cells.
     ///
@@ -1238,16 +2714,144 @@ impl NcPlane {
     /// and will be bound to the same parent. Bound planes are not duplicated;
     /// the new plane is bound to the current parent, but has no bound planes.
     ///
+    /// See also [`try_dup`][NcPlane#method.try_dup], which doesn't assume the
+    /// duplication succeeds.
+    ///
     /// *C style function: [ncplane_dup()][c_api::ncplane_dup].*
     //
-    // TODO: deal with the opaque field that is stored in NcPlaneOptions.userptr
-    //
     // SAFETY: it's a new NcPlane, not a new one
     #[allow(clippy::mut_from_ref)]
     pub fn dup(&self) -> &mut NcPlane {
         unsafe { &mut *c_api::ncplane_dup(self, null_mut()) }
     }
 
+    /// Duplicates this `NcPlane`, like [`dup`][NcPlane#method.dup], returning
+    /// an error if the duplication fails, e.g. due to memory pressure.
+    ///
+    /// The `userptr` of the new plane is null.
+    ///
+    /// *C style function: [ncplane_dup()][c_api::ncplane_dup].*
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_dup(&self) -> NcResult<&mut NcPlane> {
+        unsafe { self.dup_with_userptr(null_mut()) }
+    }
+
+    /// Duplicates this `NcPlane`, like [`try_dup`][NcPlane#method.try_dup],
+    /// setting the `userptr` of the new plane.
+    ///
+    /// A closure set with [`on_resize`][NcPlane#method.on_resize] is not
+    /// duplicated, since it's stored in the `userptr`, so in that case the
+    /// new plane has no resize callback.
+    ///
+    /// # Safety
+    /// The `userptr` is handed over to any code reading the user pointer of
+    /// the new plane, so it must be valid for whatever that code expects.
+    ///
+    /// *C style function: [ncplane_dup()][c_api::ncplane_dup].*
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn dup_with_userptr(&self, userptr: *mut c_void) -> NcResult<&mut NcPlane> {
+        let ptr = c_api::ncplane_dup(self, userptr);
+        if ptr.is_null() {
+            return Err(NcError::with_msg(
+                c_api::NCRESULT_ERR,
+                "NcPlane.dup_with_userptr()",
+            ));
+        }
+        let plane = &mut *ptr;
+        let current = c_api::ncplane_resizecb(plane);
+        let trampoline: c_api::NcResizeCbUnsafe = ncresize_closure_trampoline;
+        if current.map(|cb| cb as usize) == Some(trampoline as usize) {
+            c_api::ncplane_set_resizecb(plane, None);
+        }
+        Ok(plane)
+    }
+
+    /// Exchanges the contents of this `NcPlane` with the `other` plane.
+    ///
+    /// Every cell is swapped, keeping its `EGC`, [`NcStyle`] and
+    /// [`NcChannels`], including empty cells. The cursors, the current
+    /// styles & channels and the base cells of both planes are left unchanged.
+    ///
+    /// This allows to prepare a frame in an off-screen plane and swap it in.
+    ///
+    /// # Errors
+    /// If both planes don't have the same dimensions.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn swap_contents(&mut self, other: &mut NcPlane) -> NcResult<()> {
+        let (self_dim, other_dim) = (self.dim_yx(), other.dim_yx());
+        if self_dim != other_dim {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.swap_contents(NcPlane): size mismatch {:?} != {:?}",
+                self_dim, other_dim
+            )));
+        }
+
+        /// The `EGC`, style & channels of each cell, row by row.
+        ///
+        /// The right halves of wide glyphs are stored as `None`.
+        fn snapshot(plane: &mut NcPlane) -> NcResult<Vec<Option<(String, NcStyle, NcChannels)>>> {
+            let (rows, cols) = plane.dim_yx();
+            let mut cells = Vec::with_capacity((rows * cols) as usize);
+            for y in 0..rows {
+                for x in 0..cols {
+                    let mut cell = NcCell::new();
+                    plane.at_yx_cell(y, x, &mut cell)?;
+                    if cell.wide_right_p() {
+                        cells.push(None);
+                    } else {
+                        let egc = cell.egc(plane).to_string();
+                        cells.push(Some((egc, cell.styles(), cell.channels())));
+                    }
+                    cell.release(plane);
+                }
+            }
+            Ok(cells)
+        }
+
+        /// Replaces the plane's contents with the snapshotted cells.
+        fn restore(
+            plane: &mut NcPlane,
+            cells: &[Option<(String, NcStyle, NcChannels)>],
+        ) -> NcResult<()> {
+            let (cursor, styles, channels) = (plane.cursor_yx(), plane.styles(), plane.channels());
+            plane.erase();
+
+            let cols = plane.dim_x();
+            let (blank_style, blank_channels) = (NcStyle::None, NcChannels::from(0_u64));
+            for (i, cell) in cells.iter().enumerate() {
+                let (egc, style, chans) = match cell {
+                    Some((egc, style, chans)) => (egc, *style, *chans),
+                    None => continue,
+                };
+                let (y, x) = (i as u32 / cols, i as u32 % cols);
+                let mut cell = NcCell::new();
+                if egc.is_empty() {
+                    // an empty cell can still have a background and styles
+                    if (style, chans) == (blank_style, blank_channels) {
+                        continue;
+                    }
+                    cell.stylemask = style.0;
+                    cell.channels = chans.0;
+                } else {
+                    NcCell::prime(plane, &mut cell, egc, style, chans)?;
+                }
+                let res = plane.putc_yx(y, x, &cell);
+                cell.release(plane);
+                res?;
+            }
+
+            plane.set_styles(styles);
+            plane.set_channels(channels);
+            plane.cursor_move_yx(cursor.0, cursor.1)
+        }
+
+        let self_cells = snapshot(self)?;
+        let other_cells = snapshot(other)?;
+        restore(self, &other_cells)?;
+        restore(other, &self_cells)
+    }
+
     /// Returns the topmost `NcPlane` of the current pile.
     ///
     /// # Safety
@@ -1312,6 +2916,73 @@ impl NcPlane {
         ]
     }
 
+    /// Moves this `NcPlane` relative to its current location, as far as
+    /// allowed by its parent, and returns the `(rows, cols)` actually moved.
+    ///
+    /// If `partial` is `false` the plane is kept fully inside its parent,
+    /// (or covering it, if it's larger), otherwise it's only kept partially
+    /// inside, with at least one cell overlapping.
+    ///
+    /// A root plane is kept inside the terminal instead.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn move_rel_clamped(
+        &mut self,
+        rows: i32,
+        cols: i32,
+        partial: bool,
+    ) -> NcResult<(i32, i32)> {
+        let parent = unsafe { c_api::ncplane_parent_const(self) };
+        let (parent_y, parent_x) = if core::ptr::eq(parent, self) {
+            unsafe { self.notcurses_const()? }.term_dim_yx()
+        } else {
+            unsafe { (*parent).dim_yx() }
+        };
+        let ((y, x), (len_y, len_x)) = (self.yx(), self.dim_yx());
+
+        let clamp = |pos: i32, delta: i32, len: u32, parent_len: u32| {
+            let (len, parent_len) = (len as i32, parent_len as i32);
+            let (min, max) = if partial {
+                (1 - len, parent_len - 1)
+            } else {
+                ((parent_len - len).min(0), (parent_len - len).max(0))
+            };
+            (pos + delta).clamp(min.min(pos), max.max(pos)) - pos
+        };
+        let (rows, cols) = (
+            clamp(y, rows, len_y, parent_y),
+            clamp(x, cols, len_x, parent_x),
+        );
+        self.move_yx(y + rows, x + cols)?;
+        Ok((rows, cols))
+    }
+
+    /// Moves the `child` plane so that it's centered within this plane.
+    ///
+    /// The `child` doesn't need to be bound to this plane, its new position
+    /// is translated to the coordinates of whichever plane it's bound to.
+    ///
+    /// # Errors
+    /// If the `child` is larger than this plane in either dimension.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn center_child(&mut self, child: &mut NcPlane) -> NcResult<()> {
+        let ((len_y, len_x), (child_y, child_x)) = (self.dim_yx(), child.dim_yx());
+        if child_y > len_y || child_x > len_x {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.center_child(): child {0}x{1} doesn't fit in {2}x{3}",
+                child_y, child_x, len_y, len_x
+            )));
+        }
+        let (abs_y, abs_x) = self.abs_yx();
+        let ((child_abs_y, child_abs_x), (rel_y, rel_x)) = (child.abs_yx(), child.yx());
+        let (y, x) = (
+            abs_y + ((len_y - child_y) / 2) as i32,
+            abs_x + ((len_x - child_x) / 2) as i32,
+        );
+        child.move_yx(y - (child_abs_y - rel_y), x - (child_abs_x - rel_x))
+    }
+
     /// Returns the `NcPlane` above this one, or None if already at the top.
     ///
     /// *C style function: [ncplane_above()][c_api::ncplane_above].*
@@ -1478,6 +3149,60 @@ impl NcPlane {
         ]
     }
 
+    /// Merges the specified region of `source` down onto this `NcPlane`,
+    /// choosing how the transparent cells of `source` are treated.
+    ///
+    /// With [`NcMergeMode::Blend`] this is the same as
+    /// [`mergedown`][NcPlane#method.mergedown]: the destination shows through
+    /// wherever the source is transparent.
+    ///
+    /// With [`NcMergeMode::Replace`] the destination region is erased first,
+    /// and then `source` is composited over the erased cells, so the previous
+    /// contents are discarded even where `source` is transparent, which leaves
+    /// empty cells there. `source` is never modified.
+    ///
+    /// The region arguments behave the same as in
+    /// [`mergedown`][NcPlane#method.mergedown].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn mergedown_mode(
+        &mut self,
+        source: &mut NcPlane,
+        beg_src_y: Option<u32>,
+        beg_src_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        dst_y: Option<u32>,
+        dst_x: Option<u32>,
+        mode: NcMergeMode,
+    ) -> NcResult<()> {
+        if mode == NcMergeMode::Replace {
+            let (src_cy, src_cx) = source.cursor_yx();
+            let (src_rows, src_cols) = source.dim_yx();
+            let (beg_y, beg_x) = (beg_src_y.unwrap_or(src_cy), beg_src_x.unwrap_or(src_cx));
+            let (cy, cx) = self.cursor_yx();
+            let (rows, cols) = self.dim_yx();
+            let (dy, dx) = (dst_y.unwrap_or(cy), dst_x.unwrap_or(cx));
+
+            // a length of 0 (or None) extends to the end of the source plane
+            let ly = len_y
+                .filter(|l| *l != 0)
+                .unwrap_or(src_rows.saturating_sub(beg_y));
+            let lx = len_x
+                .filter(|l| *l != 0)
+                .unwrap_or(src_cols.saturating_sub(beg_x));
+            let (ly, lx) = (
+                ly.min(rows.saturating_sub(dy)),
+                lx.min(cols.saturating_sub(dx)),
+            );
+
+            if ly > 0 && lx > 0 {
+                self.erase_region(Some(dy), Some(dx), ly as i32, lx as i32)?;
+            }
+        }
+        self.mergedown(source, beg_src_y, beg_src_x, len_y, len_x, dst_y, dst_x)
+    }
+
     /// Merges `source` down onto this `NcPlane`.
     ///
     /// If `source` does not intersect, this plane will not be changed,
@@ -1561,6 +3286,41 @@ impl NcPlane {
         ]
     }
 
+    /// Returns the name of this `NcPlane`, or `None` if it has no name.
+    ///
+    /// *C style function: [ncplane_name()][c_api::ncplane_name].*
+    pub fn name(&self) -> Option<String> {
+        let name = unsafe { c_api::ncplane_name(self) };
+        if name.is_null() {
+            None
+        } else {
+            Some(rstring_free![name])
+        }
+    }
+
+    /// Sets the name of this `NcPlane`, replacing the previous one, if any.
+    ///
+    /// The name is only used for debugging purposes.
+    ///
+    /// *C style function: [ncplane_set_name()][c_api::ncplane_set_name].*
+    pub fn set_name(&mut self, name: &str) -> NcResult<()> {
+        let cs = cstring![name];
+        error![
+            unsafe { c_api::ncplane_set_name(self, cs.as_ptr()) },
+            &format!("NcPlane.set_name({:?})", name)
+        ]
+    }
+
+    /// Returns an [`NcPlaneWriter`][crate::NcPlaneWriter] over this `NcPlane`,
+    /// implementing [`std::io::Write`].
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn writer(&mut self) -> crate::NcPlaneWriter<'_> {
+        crate::NcPlaneWriter::new(self)
+    }
+
     /// Makes the physical screen match the last rendered frame from the pile of
     /// which this `NcPlane` is a part.
     ///
@@ -1594,23 +3354,87 @@ impl NcPlane {
         Ok(())
     }
 
+    /// Renders and rasterizes the pile of which this `NcPlane` is a part,
+    /// but only if the frame differs from the last one rasterized with the
+    /// same `tracker`.
+    ///
+    /// Returns `true` if the frame changed and was rasterized.
+    ///
+    /// See [`NcRenderTracker`] for how the frames are compared.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_if_changed(&mut self, tracker: &mut NcRenderTracker) -> NcResult<bool> {
+        match tracker.changed_hash(self)? {
+            None => Ok(false),
+            Some(hash) => {
+                self.render_raster()?;
+                tracker.set_last(hash);
+                Ok(true)
+            }
+        }
+    }
+
     /// Performs the rendering and rasterization portion of
     /// [`render`][NcPlane#method.render] and [`rasterize`][NcPlane#method.rasterize]
     /// but does not write the resulting buffer out to the terminal.
     ///
     /// Using this function, the user can control the writeout process.
-    /// The returned buffer must be freed by the caller.
+    /// The previous contents of `buffer` are replaced by the rendered frame.
     ///
     /// *C style function: [ncpile_render_to_buffer()][c_api::ncpile_render_to_buffer].*
-    // CHECK this works
     pub fn render_to_buffer(&mut self, buffer: &mut Vec<u8>) -> NcResult<()> {
-        let len = buffer.len() as u32;
-        let mut buf = buffer.as_mut_ptr() as *mut c_char;
+        let mut buf: *mut c_char = core::ptr::null_mut();
+        let mut len: usize = 0;
 
-        error![
-            unsafe { c_api::ncpile_render_to_buffer(self, &mut buf, &mut len.try_into().unwrap()) },
-            &format!["NcPlane.render_to_buffer(buffer, {})", len]
-        ]
+        let res = unsafe { c_api::ncpile_render_to_buffer(self, &mut buf, &mut len) };
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(res, "NcPlane.render_to_buffer()"));
+        }
+
+        buffer.clear();
+        if !buf.is_null() {
+            buffer.extend_from_slice(unsafe { from_raw_parts(buf as *const u8, len) });
+            unsafe { c_api::ffi::free(buf as *mut c_void) };
+        }
+        Ok(())
+    }
+
+    /// Renders the pile of which this `NcPlane` is a part, and returns the
+    /// resulting frame as a string, including the escape sequences, without
+    /// writing it to the terminal.
+    ///
+    /// This allows asserting on the exact output in tests without a tty.
+    ///
+    /// # Errors
+    /// If the rendering fails, or the output isn't valid UTF-8.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_to_ansi_string(&mut self) -> NcResult<String> {
+        String::from_utf8(self.render_bytes()?)
+            .map_err(|e| NcError::new_msg(&format!("NcPlane.render_to_ansi_string(): {}", e)))
+    }
+
+    /// Renders the pile of which this `NcPlane` is a part, and returns the
+    /// resulting frame as a string, like
+    /// [`render_to_ansi_string`][NcPlane#method.render_to_ansi_string].
+    ///
+    /// Any invalid UTF-8 in the output is replaced with `U+FFFD`, instead of
+    /// returning an error, which makes it convenient for snapshot tests
+    /// without the need of a temporary file for
+    /// [`render_to_file`][NcPlane#method.render_to_file].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_to_string(&mut self) -> NcResult<String> {
+        Ok(String::from_utf8_lossy(&self.render_bytes()?).into_owned())
+    }
+
+    /// Renders the pile of which this `NcPlane` is a part, and returns the
+    /// bytes of the resulting frame.
+    fn render_bytes(&mut self) -> NcResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.render()?;
+        self.render_to_buffer(&mut buffer)?;
+        Ok(buffer)
     }
 
     /// Writes the last rendered frame, in its entirety, to `fp`.
@@ -1658,14 +3482,32 @@ impl NcPlane {
 
     /// Returns the current position of the cursor within this `NcPlane`.
     ///
+    /// Returns `(0, 0)` if the position is not valid.
+    /// See [`try_cursor_yx`][NcPlane#method.try_cursor_yx].
+    ///
     /// *C style function: [ncplane_cursor_yx()][c_api::ncplane_cursor_yx].*
-    //
-    // NOTE: y and/or x may be NULL.
-    // check for null and return NcResult
     pub fn cursor_yx(&self) -> (u32, u32) {
-        let (mut y, mut x) = (0, 0);
+        self.try_cursor_yx().unwrap_or((0, 0))
+    }
+
+    /// Returns the current position of the cursor within this `NcPlane`,
+    /// or an error if it's not a valid position in the plane.
+    ///
+    /// The cursor column can be one past the last column, after writing
+    /// to the end of a row.
+    ///
+    /// *C style function: [ncplane_cursor_yx()][c_api::ncplane_cursor_yx].*
+    pub fn try_cursor_yx(&self) -> NcResult<(u32, u32)> {
+        let (mut y, mut x) = (u32::MAX, u32::MAX);
         unsafe { c_api::ncplane_cursor_yx(self, &mut y, &mut x) };
-        (y, x)
+        let (rows, cols) = self.dim_yx();
+        if y >= rows || x > cols {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.try_cursor_yx(): invalid cursor ({}, {}) in a {}x{} plane",
+                y, x, rows, cols
+            )));
+        }
+        Ok((y, x))
     }
 
     /// Returns the current row of the cursor within this `NcPlane`.
@@ -1682,6 +3524,19 @@ impl NcPlane {
         c_api::ncplane_cursor_x(self)
     }
 
+    /// Returns `true` if the cursor is at a drawable position within this
+    /// `NcPlane`.
+    ///
+    /// After writing to the last column the cursor sits just past it, where
+    /// further writes will fail unless scrolling is enabled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cursor_in_bounds(&self) -> bool {
+        let (y, x) = self.cursor_yx();
+        let (rows, cols) = self.dim_yx();
+        y < rows && x < cols
+    }
+
     /// Moves the cursor to the specified position within this `NcPlane`.
     ///
     /// The cursor doesn't need to be visible.
@@ -1697,6 +3552,24 @@ impl NcPlane {
         ]
     }
 
+    /// Moves the cursor to the specified position within this `NcPlane`,
+    /// like [`cursor_move_yx`][NcPlane#method.cursor_move_yx].
+    ///
+    /// If the position is out of bounds, the cursor remains unchanged and
+    /// the error contains the `(dy, dx)` distances past the last row and
+    /// column. An axis within bounds reports a distance of `0`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cursor_move_checked(&mut self, y: u32, x: u32) -> Result<(), (i32, i32)> {
+        let (rows, cols) = self.dim_yx();
+        let overflow = |pos: u32, len: u32| (pos as i64 - len as i64 + 1).max(0) as i32;
+        let (dy, dx) = (overflow(y, rows), overflow(x, cols));
+        if dy > 0 || dx > 0 {
+            return Err((dy, dx));
+        }
+        self.cursor_move_yx(y, x).map_err(|_| (dy, dx))
+    }
+
     /// Moves the cursor to the specified row within this `NcPlane`.
     ///
     /// *(No equivalent C style function)*
@@ -1807,6 +3680,19 @@ impl NcPlane {
         (y as u32, x as u32)
     }
 
+    /// Returns the number of rows that `text` would occupy when wrapped at
+    /// `width` columns, e.g. by [`puttext`][NcPlane#method.puttext].
+    ///
+    /// Words are broken at spaces, and newlines force a break. Words wider
+    /// than `width` are broken across rows, without splitting wide glyphs.
+    ///
+    /// This allows sizing a plane before creating it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn wrapped_height(text: &str, width: u32) -> u32 {
+        wrap_text(text, width).len() as u32
+    }
+
     /// Returns the dimensions of this `NcPlane`.
     ///
     /// *C style function: [ncplane_dim_yx()][c_api::ncplane_dim_yx].*
@@ -1816,6 +3702,24 @@ impl NcPlane {
         (y, x)
     }
 
+    /// Returns the dimensions of this `NcPlane`, or an error if the plane
+    /// is invalid and reports zero-sized dimensions.
+    ///
+    /// Unlike [`dim_yx`][NcPlane#method.dim_yx], this lets layout code
+    /// propagate the failure instead of working with a degenerate size.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn try_dim_yx(&self) -> NcResult<(u32, u32)> {
+        let (y, x) = self.dim_yx();
+        if y == 0 || x == 0 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.try_dim_yx(): invalid dimensions {0}x{1}",
+                y, x
+            )));
+        }
+        Ok((y, x))
+    }
+
     /// Returns the rows of this `NcPlane`.
     ///
     /// *C style function: [ncplane_dim_y()][c_api::ncplane_dim_y].*
@@ -1857,6 +3761,9 @@ impl NcPlane {
     ///
     /// Only glyphs from the specified blitset may be present.
     ///
+    /// The returned array is allocated by notcurses and never freed.
+    /// Use [`as_rgba_owned`][NcPlane#method.as_rgba_owned] to avoid the leak.
+    ///
     /// *C style function: [ncplane_as_rgba()][c_api::ncplane_as_rgba].*
     pub fn as_rgba(
         &mut self,
@@ -1895,12 +3802,164 @@ impl NcPlane {
         ]
     }
 
+    /// Creates an owned RGBA image from the selected region of the plane.
+    ///
+    /// Unlike [`as_rgba`][NcPlane#method.as_rgba], the pixels are copied
+    /// and the allocation made by notcurses is freed before returning.
+    ///
+    /// The region arguments behave the same as in [`as_rgba`][NcPlane#method.as_rgba].
+    ///
+    /// *C style function: [ncplane_as_rgba()][c_api::ncplane_as_rgba].*
+    pub fn as_rgba_owned(
+        &mut self,
+        blitter: impl Into<NcBlitter>,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<NcRgbaImage> {
+        let blitter = blitter.into();
+        let (mut pxdim_y, mut pxdim_x) = (0, 0);
+        let res_array = unsafe {
+            c_api::ncplane_as_rgba(
+                self,
+                blitter.into(),
+                beg_y.unwrap_or(u32::MAX) as i32, // -1_i32
+                beg_x.unwrap_or(u32::MAX) as i32, // "
+                len_y.unwrap_or(0),
+                len_x.unwrap_or(0),
+                &mut pxdim_y,
+                &mut pxdim_x,
+            )
+        };
+        if res_array.is_null() {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.as_rgba_owned({}, {:?}, {:?}, {:?}, {:?})",
+                blitter, beg_y, beg_x, len_y, len_x
+            )));
+        }
+        let pixels = unsafe {
+            let pixels = from_raw_parts(res_array as *const NcRgba, (pxdim_y * pxdim_x) as usize);
+            let owned = pixels.to_vec();
+            c_api::ffi::free(res_array as *mut c_void);
+            owned
+        };
+        Ok(NcRgbaImage { pixels, pxdim_y, pxdim_x })
+    }
+
+    /// Creates an RGBA flat array from the selected region of the plane,
+    /// like [`as_rgba`][NcPlane#method.as_rgba], filling the transparent
+    /// pixels with the opaque `bg` color.
+    ///
+    /// The region is `((y, x), (len_y, len_x))`, or the whole plane if `None`.
+    ///
+    /// Returns the pixels, along with the height and width of the exported
+    /// region in pixels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_rgba_with_bg(
+        &mut self,
+        blitter: impl Into<NcBlitter>,
+        region: Option<((u32, u32), (u32, u32))>,
+        bg: impl Into<NcRgb>,
+    ) -> NcResult<(Vec<NcRgba>, u32, u32)> {
+        let ((y, x), (len_y, len_x)) = region.unwrap_or(((0, 0), (0, 0)));
+        let mut image = self.as_rgba_owned(blitter, Some(y), Some(x), Some(len_y), Some(len_x))?;
+
+        // the pixels are laid out in memory as R, G, B, A bytes
+        let (r, g, b) = bg.into().into();
+        let bg = NcRgba(u32::from_ne_bytes([r, g, b, 0xFF]));
+        for px in image
+            .pixels
+            .iter_mut()
+            .filter(|px| px.0.to_ne_bytes()[3] == 0)
+        {
+            *px = bg;
+        }
+        Ok((image.pixels, image.pxdim_y, image.pxdim_x))
+    }
+
+    /// Exports the selected `region` of this `NcPlane` as a flat buffer of
+    /// bytes, with the components of each pixel in the requested `order`.
+    ///
+    /// With `region` as `Some(((y, x), (len_y, len_x)))` only that rectangle
+    /// is exported, otherwise the whole plane is.
+    ///
+    /// Returns the bytes, along with the height and width of the exported
+    /// region in pixels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_rgba_bytes(
+        &mut self,
+        blitter: impl Into<NcBlitter>,
+        region: Option<((u32, u32), (u32, u32))>,
+        order: NcChannelOrder,
+    ) -> NcResult<(Vec<u8>, u32, u32)> {
+        let ((y, x), (len_y, len_x)) = region.unwrap_or(((0, 0), (0, 0)));
+        let image = self.as_rgba_owned(blitter, Some(y), Some(x), Some(len_y), Some(len_x))?;
+
+        // the pixels are laid out in memory as R, G, B, A bytes
+        let bytes = image
+            .pixels
+            .iter()
+            .flat_map(|px| order.reorder(px.0.to_ne_bytes()))
+            .collect();
+        Ok((bytes, image.pxdim_y, image.pxdim_x))
+    }
+
+    /// Draws a scaled-down preview of this whole `NcPlane` into the `into` plane.
+    ///
+    /// This plane is exported to RGBA with `blitter`, downsampled to the pixel
+    /// size of `into` using that same blitter, and then blitted into it.
+    /// Only glyphs from the specified blitset may be present in this plane.
+    ///
+    /// This is useful for drawing minimaps and overviews.
+    ///
+    /// # Errors
+    /// If `blitter` doesn't have a fixed cell geometry
+    /// (e.g. [`Default`][NcBlitter::Default] or [`Pixel`][NcBlitter::Pixel]).
+    ///
+    /// *(No equivalent C style function)*
+    pub fn thumbnail(&mut self, into: &mut NcPlane, blitter: impl Into<NcBlitter>) -> NcResult<()> {
+        let blitter = blitter.into();
+        let (cell_h, cell_w) = blitter.cell_size().ok_or_else(|| {
+            NcError::new_msg(&format!(
+                "NcPlane.thumbnail(NcPlane, {}): unsupported blitter",
+                blitter
+            ))
+        })?;
+
+        let mut exporter = NcRgbaExporter::new(blitter);
+        let (pixels, src_h, src_w) = exporter.export(self, Some(0), Some(0), None, None)?;
+
+        // nearest-neighbor downsampling
+        let (dst_h, dst_w) = (into.dim_y() * cell_h as u32, into.dim_x() * cell_w as u32);
+        let mut data = Vec::with_capacity(dst_h as usize * dst_w as usize * 4);
+        for y in 0..dst_h {
+            let sy = (y as u64 * src_h as u64 / dst_h as u64) as usize;
+            for x in 0..dst_w {
+                let sx = (x as u64 * src_w as u64 / dst_w as u64) as usize;
+                data.extend_from_slice(&pixels[sy * src_w as usize + sx].0.to_ne_bytes());
+            }
+        }
+
+        let vopts = NcVisualOptions::builder()
+            .plane(into)
+            .blitter(blitter)
+            .region(0, 0, dst_h, dst_w)
+            .build();
+        NcBlitter::blit_rgba(&data, dst_w as usize * 4, &vopts)?;
+        Ok(())
+    }
+
     /// Returns an [`NcPixelGeometry`] structure filled with pixel geometry for
     /// the display region, each cell, and the maximum displayable bitmap.
     ///
     /// This function calls
     /// [notcurses_check_pixel_support][c_api::notcurses_check_pixel_support],
-    /// possibly leading to an interrogation of the terminal.
+    /// possibly leading to an interrogation of the terminal. Notcurses
+    /// already caches the result of the interrogation in its context, so
+    /// only the first call will ever block, and no caching is done here.
     ///
     /// *C style function: [ncplane_pixel_geom()][c_api::ncplane_pixel_geom].*
     pub fn pixel_geom(&self) -> NcPixelGeometry {
@@ -1931,6 +3990,16 @@ impl NcPlane {
         }
     }
 
+    /// Returns `true` if bitmaps can be displayed.
+    ///
+    /// Derived from the [`pixel_geom`][NcPlane#method.pixel_geom] maximum
+    /// bitmap size, which is 0 when pixels are not supported.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn supports_pixels(&self) -> bool {
+        self.pixel_geom().supports_pixels()
+    }
+
     /// Resizes this `NcPlane`.
     ///
     /// The four parameters `keep_y`, `keep_x`, `keep_len_y`, and `keep_len_x`
@@ -1983,6 +4052,55 @@ impl NcPlane {
         ]
     }
 
+    /// Resizes this `NcPlane` to `len_y` * `len_x` around the focus cell at
+    /// `focus_y` * `focus_x`, which keeps both its contents and its position
+    /// on the screen.
+    ///
+    /// The position of the focus cell within the plane is scaled
+    /// proportionally to the new size, and the plane is moved accordingly,
+    /// so that resizing behaves like zooming in and out around the focus.
+    /// The overlapping region between the old and the new extents is kept.
+    ///
+    /// # Errors
+    /// If the focus cell lies outside the plane, or the new size is 0.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn resize_around(
+        &mut self,
+        focus_y: u32,
+        focus_x: u32,
+        len_y: u32,
+        len_x: u32,
+    ) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        if focus_y >= rows || focus_x >= cols || len_y == 0 || len_x == 0 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.resize_around({}, {}, {}, {}): invalid focus or size",
+                focus_y, focus_x, len_y, len_x
+            )));
+        }
+
+        /// Returns the keep start, keep length & offset along one axis.
+        fn axis(focus: u32, old_len: u32, new_len: u32) -> (u32, u32, i32) {
+            let new_focus = (focus as u64 * new_len as u64 / old_len as u64) as i64;
+            // the new origin, in the old plane coordinates
+            let origin = focus as i64 - new_focus;
+            let keep = origin.max(0);
+            let keep_end = (origin + new_len as i64).min(old_len as i64);
+            (
+                keep as u32,
+                (keep_end - keep) as u32,
+                (origin - keep) as i32,
+            )
+        }
+        let (keep_y, keep_len_y, off_y) = axis(focus_y, rows, len_y);
+        let (keep_x, keep_len_x, off_x) = axis(focus_x, cols, len_x);
+
+        self.resize(
+            keep_y, keep_x, keep_len_y, keep_len_x, off_y, off_x, len_y, len_x,
+        )
+    }
+
     /// Suitable for use as a 'resizecb' with planes created with
     /// [`NcPlaneFlag::Marginalized`][crate::NcPlaneFlag#associatedconstant.Marginalized].
     ///
@@ -2031,13 +4149,131 @@ impl NcPlane {
         error![unsafe { c_api::ncplane_resize_realign(self) }]
     }
 
-    /// Resizes this `NcPlane`, retaining what data we can (everything, unless we're
-    /// shrinking in some dimension). Keeps the origin where it is.
+    /// Resizes this `NcPlane` against its parent, enforcing its margins, and
+    /// then realigns it, using the alignment specified at creation time.
+    ///
+    /// Suitable for use as a 'resizecb' with planes created with both the
+    /// [`Marginalized`] flag, and the [`HorAligned`] and/or [`VerAligned`] flags.
+    ///
+    /// [`Marginalized`]: crate::NcPlaneFlag#associatedconstant.Marginalized
+    /// [`HorAligned`]: crate::NcPlaneFlag#associatedconstant.HorAligned
+    /// [`VerAligned`]: crate::NcPlaneFlag#associatedconstant.VerAligned
+    ///
+    /// *(No equivalent C style function)*
+    pub fn resize_marginalized_realign(&mut self) -> NcResult<()> {
+        // the size must be recomputed first, since the alignment depends on it
+        self.resize_marginalized()?;
+        self.resize_realign()
+    }
+
+    /// Resizes this `NcPlane`, retaining what data we can (everything, unless we're
+    /// shrinking in some dimension). Keeps the origin where it is.
+    ///
+    /// *C style function: [ncplane_resize_simple()][c_api::ncplane_resize_simple].*
+    #[inline]
+    pub fn resize_simple(&mut self, len_y: u32, len_x: u32) -> NcResult<()> {
+        error![c_api::ncplane_resize_simple(self, len_y, len_x)]
+    }
+
+    /// Resizes this `NcPlane` to `len_y` * `len_x`, keeping the `anchor`
+    /// fixed on the screen, and retaining the contents that still fit.
+    ///
+    /// - [`NcAlign::Top`]/[`Left`][NcAlign::Left] anchors the top-left corner,
+    ///   like [`resize_simple`][NcPlane#method.resize_simple].
+    /// - [`NcAlign::Center`] anchors the center.
+    /// - [`NcAlign::Bottom`]/[`Right`][NcAlign::Right] anchors the
+    ///   bottom-right corner.
+    ///
+    /// The contents keep their position on the screen, so the plane is moved
+    /// in order to keep the anchor in place.
+    ///
+    /// Returns an error if `anchor` is [`NcAlign::Unaligned`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn resize_anchored(
+        &mut self,
+        len_y: u32,
+        len_x: u32,
+        anchor: impl Into<NcAlign>,
+    ) -> NcResult<()> {
+        let anchor = anchor.into();
+        let (old_y, old_x) = self.dim_yx();
+        let (diff_y, diff_x) = (old_y as i64 - len_y as i64, old_x as i64 - len_x as i64);
+        let (off_y, off_x) = match anchor {
+            NcAlign::Left => (0, 0),
+            NcAlign::Center => (diff_y / 2, diff_x / 2),
+            NcAlign::Right => (diff_y, diff_x),
+            _ => {
+                return Err(NcError::new_msg(&format!(
+                    "NcPlane.resize_anchored({}, {}, {:?}): unaligned",
+                    len_y, len_x, anchor
+                )))
+            }
+        };
+        self.resize(
+            off_y.max(0) as u32,
+            off_x.max(0) as u32,
+            old_y.min(len_y),
+            old_x.min(len_x),
+            off_y as i32,
+            off_x as i32,
+            len_y,
+            len_x,
+        )
+    }
+
+    /// Resizes the plane to the `requested` `(len_y, len_x)` size, clamped
+    /// between the `min` and `max` sizes, returning the applied size.
+    ///
+    /// This is useful in resize callbacks, to prevent the plane from
+    /// collapsing to an unusable size in a tiny terminal.
+    ///
+    /// # Errors
+    /// If `min` is larger than `max` along any axis.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn resize_clamped(
+        &mut self,
+        requested: (u32, u32),
+        min: (u32, u32),
+        max: (u32, u32),
+    ) -> NcResult<(u32, u32)> {
+        if min.0 > max.0 || min.1 > max.1 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.resize_clamped({:?}, {:?}, {:?}): min is larger than max",
+                requested, min, max
+            )));
+        }
+        let size = (
+            requested.0.clamp(min.0, max.0),
+            requested.1.clamp(min.1, max.1),
+        );
+        self.resize_simple(size.0, size.1)?;
+        Ok(size)
+    }
+
+    /// Like [`resize_simple`][NcPlane#method.resize_simple], but takes care
+    /// of the cursor falling outside of the new bounds.
     ///
-    /// *C style function: [ncplane_resize_simple()][c_api::ncplane_resize_simple].*
-    #[inline]
-    pub fn resize_simple(&mut self, len_y: u32, len_x: u32) -> NcResult<()> {
-        error![c_api::ncplane_resize_simple(self, len_y, len_x)]
+    /// If `clamp_cursor` is `true` the cursor is moved to the nearest cell
+    /// inside the new bounds, otherwise it is an error, and the plane is not
+    /// resized.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn checked_resize(&mut self, len_y: u32, len_x: u32, clamp_cursor: bool) -> NcResult<()> {
+        let (y, x) = self.cursor_yx();
+        let outside = y >= len_y || x >= len_x;
+        if outside && !clamp_cursor {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.checked_resize({}, {}, {}): the cursor at ({}, {}) would be out of bounds",
+                len_y, len_x, clamp_cursor, y, x
+            )));
+        }
+        self.resize_simple(len_y, len_x)?;
+        if outside {
+            self.cursor_move_yx(y.min(len_y - 1), x.min(len_x - 1))?;
+        }
+        Ok(())
     }
 
     /// Returns this `NcPlane`'s current resize callback, or `None` if not set.
@@ -2056,6 +4292,65 @@ impl NcPlane {
         unsafe { c_api::ncplane_set_resizecb(self, c_api::ncresizecb_to_c(resizecb)) }
     }
 
+    /// Sets a closure to be called when the parent of this `NcPlane` is
+    /// resized, receiving the old and new `(len_y, len_x)` dimensions of the
+    /// parent, which allows computing proportional layouts.
+    ///
+    /// This replaces the current resize callback, and takes over the
+    /// `userptr` of the plane, where the closure is stored. Any previous
+    /// closure is dropped.
+    ///
+    /// The closure is dropped by [`destroy`][NcPlane#method.destroy], or by
+    /// [`clear_on_resize`][NcPlane#method.clear_on_resize], which must be
+    /// called before the plane is destroyed in any other way, e.g. along with
+    /// its parent or when stopping the notcurses context.
+    ///
+    /// # Errors
+    /// If the plane already has a `userptr` not set by this method.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn on_resize(
+        &mut self,
+        cb: impl FnMut(&mut NcPlane, (u32, u32), (u32, u32)) + 'static,
+    ) -> NcResult<()> {
+        self.clear_on_resize();
+        if !unsafe { c_api::ncplane_userptr(self) }.is_null() {
+            return Err(NcError::new_msg(
+                "NcPlane.on_resize(): the plane already has a userptr",
+            ));
+        }
+        let last = unsafe { self.parent_const() }
+            .map(|p| p.dim_yx())
+            .unwrap_or_default();
+        let state = Box::new(NcResizeClosure { cb: Box::new(cb), last });
+        unsafe {
+            c_api::ncplane_set_userptr(self, Box::into_raw(state) as *mut c_void);
+            c_api::ncplane_set_resizecb(self, Some(ncresize_closure_trampoline));
+        }
+        Ok(())
+    }
+
+    /// Removes the closure set with [`on_resize`][NcPlane#method.on_resize],
+    /// along with the resize callback, and clears the `userptr`.
+    ///
+    /// Does nothing if there's no such closure.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn clear_on_resize(&mut self) {
+        let current = unsafe { c_api::ncplane_resizecb(self) };
+        let trampoline: c_api::NcResizeCbUnsafe = ncresize_closure_trampoline;
+        if current.map(|cb| cb as usize) != Some(trampoline as usize) {
+            return;
+        }
+        unsafe {
+            let state = c_api::ncplane_set_userptr(self, null_mut()) as *mut NcResizeClosure;
+            c_api::ncplane_set_resizecb(self, None);
+            if !state.is_null() {
+                drop(Box::from_raw(state));
+            }
+        }
+    }
+
     /// Rotate the plane π/2 radians (90°) clockwise.
     ///
     /// This cannot be performed on arbitrary planes, because glyphs cannot be
@@ -2087,20 +4382,48 @@ impl NcPlane {
     /// to the same absolute coordinates relative to the origin of `target`.
     ///
     /// *C style function: [ncplane_translate()][c_api::ncplane_translate].*
+    #[deprecated = "use the `translate2` method instead."]
     pub fn translate(&self, target: &NcPlane, y: &mut i32, x: &mut i32) {
         unsafe { c_api::ncplane_translate(self, target, y, x) }
     }
 
+    /// Maps the specified coordinates relative to the origin of this `NcPlane`,
+    /// to the same absolute coordinates relative to the origin of `target`.
+    ///
+    /// The returned coordinates are negative when the point lies above or to
+    /// the left of `target`.
+    ///
+    /// *C style function: [ncplane_translate()][c_api::ncplane_translate].*
+    pub fn translate2(&self, target: &NcPlane, y: u32, x: u32) -> (i32, i32) {
+        let (mut y, mut x) = (y as i32, x as i32);
+        unsafe { c_api::ncplane_translate(self, target, &mut y, &mut x) };
+        (y, x)
+    }
+
     /// Returns true if the provided absolute `y`/`x` coordinates are within
     /// this `NcPlane`, or false otherwise.
     ///
     /// Either way, translates the absolute coordinates relative to this `NcPlane`.
     ///
     /// *C style function: [ncplane_translate_abs()][c_api::ncplane_translate_abs].*
+    #[deprecated = "use the `translate_abs2` method instead."]
     pub fn translate_abs(&self, y: &mut i32, x: &mut i32) -> bool {
         unsafe { c_api::ncplane_translate_abs(self, y, x) }
     }
 
+    /// Translates the absolute `y`/`x` coordinates relative to this `NcPlane`.
+    ///
+    /// Returns the translated coordinates, which are negative when the point
+    /// lies above or to the left of this plane, and whether the point falls
+    /// within this plane.
+    ///
+    /// *C style function: [ncplane_translate_abs()][c_api::ncplane_translate_abs].*
+    pub fn translate_abs2(&self, y: u32, x: u32) -> (i32, i32, bool) {
+        let (mut y, mut x) = (y as i32, x as i32);
+        let within = unsafe { c_api::ncplane_translate_abs(self, &mut y, &mut x) };
+        (y, x, within)
+    }
+
     /// Gets the `y`, `x` origin of this `NcPlane` relative to its parent,
     /// or its pile, if it's a root plane.
     ///
@@ -2192,6 +4515,84 @@ impl NcPlane {
         error![res, "", res as u32]
     }
 
+    /// Scrolls the content of the plane `n` lines down, the opposite of
+    /// [`scrollup`][NcPlane#method.scrollup].
+    ///
+    /// The content is shifted down, discarding the lines at the bottom and
+    /// leaving empty lines at the top, where the base cell shows through.
+    /// The cursor is left unchanged.
+    ///
+    /// Returns the number of lines scrolled, or an error if the plane is not
+    /// a scrolling plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scrolldown(&mut self, n: u32) -> NcResult<u32> {
+        if !self.scrolling_p() {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.scrolldown({}): the plane is not scrolling",
+                n
+            )));
+        }
+
+        // there's no scrolldown in the C API, so the rows are shifted down
+        let (rows, cols) = self.dim_yx();
+        let n = n.min(rows);
+        let cursor = self.cursor_yx();
+        for y in (n..rows).rev() {
+            for x in 0..cols {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y - n, x, &mut cell)?;
+                let res = if cell.wide_right_p() {
+                    Ok(())
+                } else if cell.egc(self).is_empty() {
+                    // an empty cell can still have a background and styles
+                    let mut blank = NcCell::new();
+                    blank.stylemask = cell.stylemask;
+                    blank.channels = cell.channels;
+                    self.erase_region(Some(y), Some(x), 1, 1).and_then(|_| {
+                        if (blank.stylemask, blank.channels) == (0, 0) {
+                            Ok(())
+                        } else {
+                            self.putc_yx(y, x, &blank).map(|_| ())
+                        }
+                    })
+                } else {
+                    self.putc_yx(y, x, &cell).map(|_| ())
+                };
+                cell.release(self);
+                res?;
+            }
+        }
+        if n > 0 {
+            self.erase_region(Some(0), Some(0), n as i32, cols as i32)?;
+        }
+        self.cursor_move_yx(cursor.0, cursor.1)?;
+        Ok(n)
+    }
+
+    /// Scrolls the plane `n` lines up if positive, or down if negative.
+    ///
+    /// See [`scrollup`][NcPlane#method.scrollup] and
+    /// [`scrolldown`][NcPlane#method.scrolldown].
+    ///
+    /// Returns the number of lines scrolled, or an error if the plane is not
+    /// a scrolling plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scroll_lines(&mut self, n: i32) -> NcResult<u32> {
+        if !self.scrolling_p() {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.scroll_lines({}): the plane is not scrolling",
+                n
+            )));
+        }
+        if n >= 0 {
+            self.scrollup(n as u32)
+        } else {
+            self.scrolldown(n.unsigned_abs())
+        }
+    }
+
     /// Scrolls the current plane until `child` is no longer hidden beneath it.
     ///
     /// Returns an error if `child` is not a child of this plane, or if this
@@ -2235,6 +4636,29 @@ impl NcPlane {
     pub fn set_autogrow(&mut self, autogrow: bool) -> bool {
         unsafe { c_api::ncplane_set_autogrow(self, autogrow.into()) }
     }
+
+    /// Sets what to do with the output that reaches the boundary of the plane,
+    /// configuring both scrolling and autogrow consistently.
+    ///
+    /// See [`NcTextFlow`] for the details of each variant.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_text_flow(&mut self, flow: NcTextFlow) {
+        let (scroll, autogrow) = match flow {
+            NcTextFlow::Clip => (false, false),
+            NcTextFlow::Scroll => (true, false),
+            NcTextFlow::Grow => (false, true),
+        };
+        // disable first, so that both are never enabled at the same time
+        if !scroll {
+            self.set_scrolling(false);
+        }
+        if !autogrow {
+            self.set_autogrow(false);
+        }
+        self.set_scrolling(scroll);
+        self.set_autogrow(autogrow);
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -2318,6 +4742,229 @@ impl NcPlane {
         )]
     }
 
+    /// Draws a box with its upper-left corner at the current cursor position,
+    /// like [`box_sized`][NcPlane#method.box_sized], and then restores the
+    /// cursor to the upper-left corner of the box.
+    ///
+    /// notcurses leaves the cursor wherever it drew the last border cell,
+    /// which depends on the `boxmask`. This method normalizes that, and
+    /// returns the resulting cursor position.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn box_sized_homed(
+        &mut self,
+        ul: &NcCell,
+        ur: &NcCell,
+        ll: &NcCell,
+        lr: &NcCell,
+        hline: &NcCell,
+        vline: &NcCell,
+        len_y: u32,
+        len_x: u32,
+        boxmask: impl Into<NcBoxMask>,
+    ) -> NcResult<(u32, u32)> {
+        let (y, x) = self.cursor_yx();
+        let res = self.box_sized(ul, ur, ll, lr, hline, vline, len_y, len_x, boxmask);
+        self.cursor_move_yx(y, x)?;
+        res?;
+        Ok((y, x))
+    }
+
+    /// Draws a box with its upper-left corner at the current cursor position,
+    /// having dimensions `len_y` * `len_x`, using the six `EGC`s in `glyphs`
+    /// for the upper-left, ur, ll, and lr corners, and then the horizontal and
+    /// vertical lines.
+    ///
+    /// Returns the interior of the box, inside the border, as a tuple of
+    /// `((y, x), (len_y, len_x))`, with the coordinates relative to this plane.
+    ///
+    /// See the [`box`][NcPlane#method.box] method for more information.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn box_sized_interior(
+        &mut self,
+        glyphs: &str,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        len_y: u32,
+        len_x: u32,
+        boxmask: impl Into<NcBoxMask>,
+    ) -> NcResult<((u32, u32), (u32, u32))> {
+        let (y, x) = self.cursor_yx();
+        let (mut ul, mut ur, mut ll) = (NcCell::new(), NcCell::new(), NcCell::new());
+        let (mut lr, mut hl, mut vl) = (NcCell::new(), NcCell::new(), NcCell::new());
+        NcCell::load_box(
+            self, style, channels, &mut ul, &mut ur, &mut ll, &mut lr, &mut hl, &mut vl, glyphs,
+        )?;
+        let res = self.box_sized(&ul, &ur, &ll, &lr, &hl, &vl, len_y, len_x, boxmask);
+        for cell in [&mut ul, &mut ur, &mut ll, &mut lr, &mut hl, &mut vl] {
+            cell.release(self);
+        }
+        res?;
+        Ok((
+            (y + 1, x + 1),
+            (len_y.saturating_sub(2), len_x.saturating_sub(2)),
+        ))
+    }
+
+    /// Draws a box one cell outside of the `child` plane, using the six `EGC`s
+    /// in `glyphs`, like [`box_sized_interior`][NcPlane#method.box_sized_interior].
+    ///
+    /// The position of `child` is taken relative to this plane, so it doesn't
+    /// need to be actually bound to it.
+    ///
+    /// # Errors
+    /// If the border would fall off this plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn box_around(
+        &mut self,
+        child: &NcPlane,
+        glyphs: &str,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        boxmask: impl Into<NcBoxMask>,
+    ) -> NcResult<()> {
+        let ((self_y, self_x), (child_y, child_x)) = (self.abs_yx(), child.abs_yx());
+        let (y, x) = (child_y - self_y - 1, child_x - self_x - 1);
+        let (len_y, len_x) = (child.dim_y() + 2, child.dim_x() + 2);
+        let (rows, cols) = self.dim_yx();
+        if y < 0 || x < 0 || y as u32 + len_y > rows || x as u32 + len_x > cols {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.box_around(): the border at ({}, {}) sized {}x{} falls off the plane",
+                y, x, len_y, len_x
+            )));
+        }
+        self.cursor_move_yx(y as u32, x as u32)?;
+        self.box_sized_interior(glyphs, style, channels, len_y, len_x, boxmask)?;
+        Ok(())
+    }
+
+    /// Draws a framed panel at `(y, x)` sized `len_y` * `len_x`, using the six
+    /// `EGC`s in `glyphs` and `border_channels` for the border, with an
+    /// optional `title` centered on the top border.
+    ///
+    /// A title wider than the interior of the panel is truncated.
+    ///
+    /// Returns the origin of the interior, where its content can be written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn panel(
+        &mut self,
+        y: u32,
+        x: u32,
+        len_y: u32,
+        len_x: u32,
+        glyphs: &str,
+        border_channels: impl Into<NcChannels>,
+        title: Option<&str>,
+    ) -> NcResult<(u32, u32)> {
+        let border_channels = border_channels.into();
+        self.cursor_move_yx(y, x)?;
+        let ((int_y, int_x), (_, int_len_x)) = self.box_sized_interior(
+            glyphs,
+            NcStyle::None,
+            border_channels,
+            len_y,
+            len_x,
+            NcBoxMask::None,
+        )?;
+
+        if let Some(title) = title {
+            let (truncated, width) = truncate_to_width(title, int_len_x);
+            if !truncated.is_empty() {
+                let prev_channels = self.channels();
+                self.set_channels(border_channels);
+                let res = self.putstr_yx(Some(y), Some(int_x + (int_len_x - width) / 2), truncated);
+                self.set_channels(prev_channels);
+                res?;
+            }
+        }
+        self.cursor_move_yx(int_y, int_x)?;
+        Ok((int_y, int_x))
+    }
+
+    /// Overlays a `title` onto an already drawn box border spanning the whole
+    /// plane, at the chosen `edge` and `align`ment.
+    ///
+    /// The title is surrounded by a padding space on each side, and it's
+    /// placed between the corners, being truncated if it doesn't fit.
+    /// Nothing is drawn if there's no room for at least one column of text.
+    ///
+    /// The cursor, styles and channels of the plane are restored afterwards.
+    ///
+    /// Returns an error if `align` is [`NcAlign::Unaligned`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_box_title(
+        &mut self,
+        title: &str,
+        edge: NcBoxEdge,
+        align: impl Into<NcAlign>,
+        style: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+    ) -> NcResult<()> {
+        let align = align.into();
+        if align == NcAlign::Unaligned {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.set_box_title({:?}, {:?}, {:?}): unaligned",
+                title, edge, align
+            )));
+        }
+        let (rows, cols) = self.dim_yx();
+        let room = cols.saturating_sub(4);
+
+        let (truncated, width) = truncate_to_width(title, room);
+        if truncated.is_empty() {
+            return Ok(());
+        }
+
+        let padded_width = width + 2;
+        let x = match align {
+            NcAlign::Left => 1,
+            NcAlign::Center => 1 + (cols - 2 - padded_width) / 2,
+            NcAlign::Right => cols - 1 - padded_width,
+            _ => unreachable!(),
+        };
+        let y = match edge {
+            NcBoxEdge::Top => 0,
+            NcBoxEdge::Bottom => rows - 1,
+        };
+
+        let prev_cursor = self.cursor_yx();
+        let (prev_styles, prev_channels) = (self.styles(), self.channels());
+        self.set_styles(style);
+        self.set_channels(channels);
+        let res = self.putstr_yx(Some(y), Some(x), &format!(" {} ", truncated));
+        self.set_styles(prev_styles);
+        self.set_channels(prev_channels);
+        self.cursor_move_yx(prev_cursor.0, prev_cursor.1)?;
+        res.map(|_| ())
+    }
+
+    /// Returns the size `(len_y, len_x)` of a box whose interior fits a
+    /// `content` of size `(len_y, len_x)`, adding the border on each side.
+    ///
+    /// This is the inverse of [`content_size_for`][NcPlane#method.content_size_for].
+    ///
+    /// *(No equivalent C style function)*
+    pub const fn box_size_for(content: (u32, u32)) -> (u32, u32) {
+        (content.0.saturating_add(2), content.1.saturating_add(2))
+    }
+
+    /// Returns the size `(len_y, len_x)` of the interior of a box of size
+    /// `box_size`, removing the border on each side.
+    ///
+    /// A box smaller than 2 cells along any dimension has no interior
+    /// along it.
+    ///
+    /// This is the inverse of [`box_size_for`][NcPlane#method.box_size_for].
+    ///
+    /// *(No equivalent C style function)*
+    pub const fn content_size_for(box_size: (u32, u32)) -> (u32, u32) {
+        (box_size.0.saturating_sub(2), box_size.1.saturating_sub(2))
+    }
+
     /// NcPlane.[`box`][NcPlane#method.box] with ASCII characters.
     ///
     /// *C style function: [ncplane_ascii_box()][c_api::ncplane_ascii_box].*
@@ -2340,6 +4987,58 @@ impl NcPlane {
         )]
     }
 
+    /// NcPlane.[`box`][NcPlane#method.box] with the characters of the
+    /// `glyphs` set, all of them using the same `stylemask` and `channels`.
+    ///
+    /// This covers the sets that don't have a dedicated method, like the
+    /// heavy and light ones.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn box_styled(
+        &mut self,
+        stylemask: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        stop_y: u32,
+        stop_x: u32,
+        boxmask: impl Into<NcBoxMask>,
+        glyphs: NcBoxGlyphs,
+    ) -> NcResult<()> {
+        let (stylemask, channels) = (stylemask.into(), channels.into());
+        let [mut ul, mut ur, mut ll, mut lr, mut hl, mut vl] = [NcCell::new(); 6];
+        let mut res = c_api::nccells_load_box(
+            self,
+            stylemask,
+            channels,
+            &mut ul,
+            &mut ur,
+            &mut ll,
+            &mut lr,
+            &mut hl,
+            &mut vl,
+            glyphs.egcs(),
+        );
+        if res == c_api::NCRESULT_OK {
+            res = unsafe {
+                c_api::ncplane_box(
+                    self,
+                    &ul,
+                    &ur,
+                    &ll,
+                    &lr,
+                    &hl,
+                    &vl,
+                    stop_y,
+                    stop_x,
+                    boxmask.into().0,
+                )
+            };
+        }
+        for cell in [&mut ul, &mut ur, &mut ll, &mut lr, &mut hl, &mut vl] {
+            cell.release(self);
+        }
+        error![res, &format!("NcPlane.box_styled({:?})", glyphs)]
+    }
+
     /// NcPlane.[`box`][NcPlane#method.box] with the double box-drawing characters.
     ///
     /// *C style function: [ncplane_double_box()][c_api::ncplane_double_box].*
@@ -2509,6 +5208,44 @@ impl NcPlane {
         error![unsafe { c_api::ncplane_pulse(self, time, fader, null_mut()) }]
     }
 
+    /// Fades this `NcPlane` in over `in_time`, holds it for `hold`, and then
+    /// fades it out over `out_time`, calling `fader` at each iteration.
+    ///
+    /// Proper use involves preparing (but not rendering) the `NcPlane`,
+    /// then calling this method.
+    ///
+    /// If the terminal doesn't support fading, the plane is just rendered,
+    /// held, and then rendered again with its contents hidden, which are
+    /// restored afterwards, without rendering.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "libc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "libc")))]
+    pub fn flash(
+        &mut self,
+        in_time: &NcTime,
+        hold: &NcTime,
+        out_time: &NcTime,
+        fader: NcFadeCb,
+    ) -> NcResult<()> {
+        if unsafe { self.notcurses_const()?.canfade() } {
+            self.fadein(in_time, fader)?;
+            sleep(hold);
+            self.fadeout(out_time, fader)
+        } else {
+            self.render_raster()?;
+            sleep(hold);
+
+            // the contents are moved out while hidden, and then moved back
+            let (rows, cols) = self.dim_yx();
+            let stash = NcPlane::new_pile_sized(unsafe { self.notcurses()? }, 0, 0, rows, cols)?;
+            let res = self.swap_contents(stash).and_then(|_| self.render_raster());
+            let restored = self.swap_contents(stash);
+            stash.destroy()?;
+            res.and(restored)
+        }
+    }
+
     /// Draws a gradient with its upper-left corner at the current cursor
     /// position, stopping at `stop_y` * `stop_x`.
     ///
@@ -2566,6 +5303,110 @@ impl NcPlane {
         error![res, "", res as u32]
     }
 
+    /// Draws a vertical gradient from the `top` channels to the `bottom` ones,
+    /// setting up the four corners of [`gradient`][NcPlane#method.gradient]
+    /// so that its preconditions hold.
+    ///
+    /// The region arguments behave the same as in `gradient`. If the region
+    /// is a single row high, it's filled with the `top` channels.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn gradient_vertical(
+        &mut self,
+        y: Option<u32>,
+        x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        egc: &str,
+        stylemask: impl Into<NcStyle>,
+        top: impl Into<NcChannels>,
+        bottom: impl Into<NcChannels>,
+    ) -> NcResult<u32> {
+        let beg_y = y.unwrap_or_else(|| self.cursor_y());
+        let rows = len_y
+            .filter(|l| *l != 0)
+            .unwrap_or_else(|| self.dim_y().saturating_sub(beg_y));
+        let top = top.into();
+        let bottom = if rows == 1 { top } else { bottom.into() };
+        self.gradient(y, x, len_y, len_x, egc, stylemask, top, top, bottom, bottom)
+    }
+
+    /// Draws a horizontal gradient from the `left` channels to the `right`
+    /// ones, setting up the four corners of [`gradient`][NcPlane#method.gradient]
+    /// so that its preconditions hold.
+    ///
+    /// The region arguments behave the same as in `gradient`. If the region
+    /// is a single column wide, it's filled with the `left` channels.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn gradient_horizontal(
+        &mut self,
+        y: Option<u32>,
+        x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        egc: &str,
+        stylemask: impl Into<NcStyle>,
+        left: impl Into<NcChannels>,
+        right: impl Into<NcChannels>,
+    ) -> NcResult<u32> {
+        let beg_x = x.unwrap_or_else(|| self.cursor_x());
+        let cols = len_x
+            .filter(|l| *l != 0)
+            .unwrap_or_else(|| self.dim_x().saturating_sub(beg_x));
+        let left = left.into();
+        let right = if cols == 1 { left } else { right.into() };
+        self.gradient(y, x, len_y, len_x, egc, stylemask, left, right, left, right)
+    }
+
+    /// Stains each cell of the `region` with the channels returned by `f`,
+    /// which receives the normalized `(y, x)` position of the cell in the
+    /// region, in the range `0.0..=1.0`.
+    ///
+    /// This allows drawing heatmaps and any other color fields, beyond the
+    /// four-corner interpolation of [`stain`][NcPlane#method.stain].
+    ///
+    /// The region is `((y, x), (len_y, len_x))`, or the whole plane if `None`.
+    ///
+    /// Returns the number of cells stained.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn gradient_fn(
+        &mut self,
+        region: Option<((u32, u32), (u32, u32))>,
+        f: impl Fn(f32, f32) -> NcChannels,
+    ) -> NcResult<usize> {
+        let (rows, cols) = self.dim_yx();
+        let ((y, x), (len_y, len_x)) = region.unwrap_or(((0, 0), (rows, cols)));
+        if y as u64 + len_y as u64 > rows as u64 || x as u64 + len_x as u64 > cols as u64 {
+            return Err(NcError::new_msg(&format!(
+                "NcPlane.gradient_fn({:?}): the region doesn't fit in the plane",
+                region
+            )));
+        }
+        let norm = |i: u32, len: u32| if len > 1 { i as f32 / (len - 1) as f32 } else { 0.0 };
+        for dy in 0..len_y {
+            for dx in 0..len_x {
+                let channels = f(norm(dy, len_y), norm(dx, len_x));
+                self.stain(
+                    Some(y + dy),
+                    Some(x + dx),
+                    Some(1),
+                    Some(1),
+                    channels,
+                    channels,
+                    channels,
+                    channels,
+                )?;
+            }
+        }
+        Ok(len_y as usize * len_x as usize)
+    }
+
     /// Does a high-resolution gradient using upper blocks and synced backgrounds.
     ///
     /// This doubles the number of vertical gradations, but restricts you to
@@ -2607,6 +5448,52 @@ impl NcPlane {
         error![res, "", res as u32]
     }
 
+    /// Like [`gradient2x1`][NcPlane#method.gradient2x1], but also returns the
+    /// channels actually drawn at the four corners, in the order
+    /// `[ul, ur, ll, lr]`.
+    ///
+    /// This is useful for verifying that a gradient matches the expectations.
+    /// The upper corners are read from the foreground of the upper cells,
+    /// and the lower corners from the background of the lower cells.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn gradient2x1_debug(
+        &mut self,
+        y: Option<u32>,
+        x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+        ul: impl Into<NcChannel>,
+        ur: impl Into<NcChannel>,
+        ll: impl Into<NcChannel>,
+        lr: impl Into<NcChannel>,
+    ) -> NcResult<(u32, [NcChannel; 4])> {
+        // the region must be resolved before drawing moves the cursor
+        let ((by, bx), (ly, lx)) = self.resolve_region(y, x, len_y, len_x).ok_or_else(|| {
+            NcError::new_msg(&format!(
+                "NcPlane.gradient2x1_debug({:?}, {:?}, {:?}, {:?}): out of bounds",
+                y, x, len_y, len_x
+            ))
+        })?;
+
+        let filled = self.gradient2x1(y, x, len_y, len_x, ul, ur, ll, lr)?;
+
+        let (last_y, last_x) = (by + ly - 1, bx + lx - 1);
+        let mut channels_at = |y, x| -> NcResult<NcChannels> {
+            let mut cell = NcCell::new();
+            self.at_yx_cell(y, x, &mut cell)?;
+            cell.release(self);
+            Ok(cell.channels())
+        };
+        let corners = [
+            channels_at(by, bx)?.fchannel(),
+            channels_at(by, last_x)?.fchannel(),
+            channels_at(last_y, bx)?.bchannel(),
+            channels_at(last_y, last_x)?.bchannel(),
+        ];
+        Ok((filled, corners))
+    }
+
     /// Converts this `NcPlane`'s content to greyscale.
     ///
     /// *C style function: [ncplane_greyscale()][c_api::ncplane_greyscale].*
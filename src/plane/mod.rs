@@ -1,9 +1,9 @@
 //! `NcPlane`
 
-// functions already exported by bindgen : 100
+// functions already exported by bindgen : 102
 // -------------------------------------------
-// (#) test: 9
-// (W) wrap: 91
+// (#) test: 10
+// (W) wrap: 93
 // -------------------------------------------
 //W  ncpile_bottom
 //W# ncpile_create
@@ -52,6 +52,7 @@
 //W  ncplane_move_family_above
 //W  ncplane_move_family_below
 //W  ncplane_move_yx
+//W# ncplane_name
 //W# ncplane_notcurses
 //W# ncplane_notcurses_const
 //W  ncplane_off_styles
@@ -92,6 +93,7 @@
 //W  ncplane_set_fg_palindex
 //W  ncplane_set_fg_rgb
 //   ncplane_set_fg_rgb8           // unneeded method
+//W  ncplane_set_name
 //W  ncplane_set_resizecb
 //W  ncplane_set_scrolling
 //W  ncplane_set_styles
@@ -185,14 +187,45 @@
 //   ncplane_putwstr_yx
 //   ncplane_vprintf
 
+mod box_edge;
+mod box_glyphs;
+mod channel_order;
+mod control_policy;
 pub(crate) mod helpers;
+mod merge_mode;
 mod methods;
 pub(crate) mod options;
 pub(crate) mod reimplemented;
+mod render_tracker;
+mod rgba_exporter;
+mod rgba_image;
+mod scroll_region;
+mod scrollback;
+mod sprixel;
 #[cfg(test)]
 pub(crate) mod test;
+mod text_flow;
+mod viewport;
+#[cfg(feature = "std")]
+mod writer;
 
+pub use box_edge::NcBoxEdge;
+pub use box_glyphs::NcBoxGlyphs;
+pub use channel_order::NcChannelOrder;
+pub use control_policy::NcControlPolicy;
+pub use merge_mode::NcMergeMode;
 pub use options::{NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+pub use render_tracker::NcRenderTracker;
+pub use rgba_exporter::NcRgbaExporter;
+pub use rgba_image::NcRgbaImage;
+pub use scroll_region::NcScrollRegion;
+pub use scrollback::NcScrollback;
+pub use sprixel::NcSprixel;
+pub use text_flow::NcTextFlow;
+pub use viewport::NcViewport;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use writer::NcPlaneWriter;
 
 // NcPlane
 //
@@ -0,0 +1,106 @@
+//! `NcRenderTracker`
+
+use core::hash::{Hash, Hasher};
+
+use crate::{c_api, NcCell, NcPlane, NcResult};
+
+/// Remembers the contents of the last frame rasterized with
+/// [`NcPlane.render_if_changed`], so that unchanged frames can be skipped.
+///
+/// Only a hash of the frame is kept, computed from the contents of every
+/// plane in the pile (their cells, base cells, positions and sizes) and the
+/// size of the terminal, which together determine the composited frame.
+///
+/// Each pile should have its own tracker.
+///
+/// [`NcPlane.render_if_changed`]: crate::NcPlane#method.render_if_changed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NcRenderTracker {
+    last: Option<u64>,
+}
+
+impl NcRenderTracker {
+    /// New `NcRenderTracker`, without any previous frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the hash of the last frame, if any has been rasterized.
+    pub fn last_hash(&self) -> Option<u64> {
+        self.last
+    }
+
+    /// Forgets the last frame, so that the next one is always rasterized.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Returns the hash of the current frame of the pile of which `plane` is a
+    /// part, or `None` if it's the same as the last one.
+    pub(crate) fn changed_hash(&self, plane: &mut NcPlane) -> NcResult<Option<u64>> {
+        let hash = pile_hash(plane)?;
+        Ok(if self.last == Some(hash) { None } else { Some(hash) })
+    }
+
+    /// Remembers `hash` as the one of the last rasterized frame.
+    pub(crate) fn set_last(&mut self, hash: u64) {
+        self.last = Some(hash);
+    }
+}
+
+/// Hashes the contents of all the planes in the pile of which `plane` is a
+/// part, from top to bottom, and the size of the terminal.
+fn pile_hash(plane: &mut NcPlane) -> NcResult<u64> {
+    let mut hasher = Fnv1a::default();
+    unsafe { plane.notcurses_const()? }
+        .term_dim_yx()
+        .hash(&mut hasher);
+
+    let mut ptr = unsafe { c_api::ncpile_top(plane) };
+    while !ptr.is_null() {
+        let plane = unsafe { &mut *ptr };
+        (plane.abs_yx(), plane.dim_yx()).hash(&mut hasher);
+
+        let mut base = plane.base()?;
+        hash_cell(plane, &base, &mut hasher);
+        base.release(plane);
+
+        let (rows, cols) = plane.dim_yx();
+        for y in 0..rows {
+            for x in 0..cols {
+                let mut cell = NcCell::new();
+                plane.at_yx_cell(y, x, &mut cell)?;
+                hash_cell(plane, &cell, &mut hasher);
+                cell.release(plane);
+            }
+        }
+        ptr = unsafe { c_api::ncplane_below(plane) };
+    }
+    Ok(hasher.finish())
+}
+
+/// Hashes the `EGC`, the styles and the channels of a `cell` of `plane`.
+fn hash_cell(plane: &NcPlane, cell: &NcCell, hasher: &mut Fnv1a) {
+    (cell.egc(plane), cell.stylemask, cell.channels).hash(hasher);
+}
+
+/// The 64-bit FNV-1a hash.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
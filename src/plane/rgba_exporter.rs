@@ -0,0 +1,85 @@
+//! `NcRgbaExporter`
+
+use core::slice::from_raw_parts;
+
+use crate::{c_api, NcBlitter, NcError, NcPlane, NcResult, NcRgba};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// Exports regions of an [`NcPlane`] as flat RGBA arrays, reusing the same
+/// buffer between calls.
+///
+/// Notcurses still allocates a new array on every export, which is freed
+/// right after its pixels are copied into an owned buffer that only grows when
+/// needed, so the returned slice doesn't need to be freed by the caller.
+///
+/// [`NcPlane.as_rgba`]: crate::NcPlane#method.as_rgba
+#[derive(Clone, Debug)]
+pub struct NcRgbaExporter {
+    blitter: NcBlitter,
+    buffer: Vec<NcRgba>,
+}
+
+impl NcRgbaExporter {
+    /// New `NcRgbaExporter` for the provided `blitter`.
+    pub fn new(blitter: impl Into<NcBlitter>) -> Self {
+        Self { blitter: blitter.into(), buffer: Vec::new() }
+    }
+
+    /// Returns the blitter used for exporting.
+    pub fn blitter(&self) -> NcBlitter {
+        self.blitter
+    }
+
+    /// Returns the current capacity of the reusable buffer, in pixels.
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Exports the selected region of the `plane`, returning the RGBA pixels,
+    /// along with the height and width of the exported region in pixels.
+    ///
+    /// The region arguments behave the same as in [`NcPlane.as_rgba`].
+    ///
+    /// The returned slice is overwritten by the next export.
+    ///
+    /// [`NcPlane.as_rgba`]: crate::NcPlane#method.as_rgba
+    pub fn export(
+        &mut self,
+        plane: &mut NcPlane,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<(&[NcRgba], u32, u32)> {
+        let mut pxdim_y = 0;
+        let mut pxdim_x = 0;
+        let res_array = unsafe {
+            c_api::ncplane_as_rgba(
+                plane,
+                self.blitter.into(),
+                beg_y.unwrap_or(u32::MAX) as i32, // -1_i32
+                beg_x.unwrap_or(u32::MAX) as i32, // "
+                len_y.unwrap_or(0),
+                len_x.unwrap_or(0),
+                &mut pxdim_y,
+                &mut pxdim_x,
+            )
+        };
+        if res_array.is_null() {
+            return Err(NcError::new_msg(&format!(
+                "NcRgbaExporter.export({}, {:?}, {:?}, {:?}, {:?})",
+                self.blitter, beg_y, beg_x, len_y, len_x
+            )));
+        }
+
+        self.buffer.clear();
+        unsafe {
+            let pixels = from_raw_parts(res_array as *const NcRgba, (pxdim_y * pxdim_x) as usize);
+            self.buffer.extend_from_slice(pixels);
+            c_api::ffi::free(res_array as *mut core::ffi::c_void);
+        }
+        Ok((&self.buffer, pxdim_y, pxdim_x))
+    }
+}
@@ -0,0 +1,32 @@
+//! `NcRgbaImage`
+
+use crate::NcRgba;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// An owned RGBA image exported from an [`NcPlane`][crate::NcPlane].
+///
+/// The pixels are laid out row by row, with `pxdim_y` rows of `pxdim_x`
+/// pixels each.
+///
+/// See [`NcPlane.as_rgba_owned`][crate::NcPlane#method.as_rgba_owned].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcRgbaImage {
+    /// The pixels, row by row.
+    pub pixels: Vec<NcRgba>,
+    /// The height in pixels.
+    pub pxdim_y: u32,
+    /// The width in pixels.
+    pub pxdim_x: u32,
+}
+
+impl NcRgbaImage {
+    /// Returns the pixel at `y`, `x`, or `None` if it's out of bounds.
+    pub fn pixel(&self, y: u32, x: u32) -> Option<NcRgba> {
+        if y >= self.pxdim_y || x >= self.pxdim_x {
+            return None;
+        }
+        self.pixels.get((y * self.pxdim_x + x) as usize).copied()
+    }
+}
@@ -0,0 +1,116 @@
+//! `NcScrollRegion`
+
+use crate::{NcError, NcPlane, NcPlaneOptions, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// A fixed header above a scrolling body, inside of a parent [`NcPlane`].
+///
+/// notcurses scrolls whole planes, so the header and the body are two child
+/// planes of the parent: the header spans its top rows, and the body the
+/// rest of it, with scrolling enabled.
+///
+/// After resizing the parent call [`resize`] to lay them out again.
+///
+/// [`resize`]: NcScrollRegion#method.resize
+#[derive(Debug)]
+pub struct NcScrollRegion<'a> {
+    parent: &'a mut NcPlane,
+    header: &'a mut NcPlane,
+    body: &'a mut NcPlane,
+    header_rows: u32,
+    empty: bool,
+}
+
+impl<'a> NcScrollRegion<'a> {
+    /// New `NcScrollRegion` inside of `parent`, with a header of
+    /// `header_rows` rows.
+    ///
+    /// # Errors
+    /// If the parent doesn't have any rows left for the body.
+    pub fn new(parent: &'a mut NcPlane, header_rows: u32) -> NcResult<Self> {
+        let (rows, cols) = parent.dim_yx();
+        if header_rows >= rows {
+            return Err(NcError::new_msg(&format!(
+                "NcScrollRegion::new(NcPlane, {}): no rows left for the body in a {}x{} plane",
+                header_rows, rows, cols
+            )));
+        }
+        let header = NcPlane::new_child(parent, &NcPlaneOptions::new(0, 0, header_rows, cols))?;
+        let body = NcPlane::new_child(
+            parent,
+            &NcPlaneOptions::new(header_rows as i32, 0, rows - header_rows, cols),
+        )?;
+        body.set_scrolling(true);
+        Ok(Self { parent, header, body, header_rows, empty: true })
+    }
+
+    /// Destroys the header and body planes.
+    pub fn destroy(self) -> NcResult<()> {
+        self.header.destroy()?;
+        self.body.destroy()
+    }
+
+    /// Returns a reference to the parent plane.
+    pub fn parent(&mut self) -> &mut NcPlane {
+        self.parent
+    }
+
+    /// Returns a reference to the header plane.
+    pub fn header(&mut self) -> &mut NcPlane {
+        self.header
+    }
+
+    /// Returns a reference to the body plane.
+    pub fn body(&mut self) -> &mut NcPlane {
+        self.body
+    }
+
+    /// Returns the number of rows of the header.
+    pub fn header_rows(&self) -> u32 {
+        self.header_rows
+    }
+
+    /// Replaces the contents of the header with `text`.
+    pub fn set_header(&mut self, text: &str) -> NcResult<()> {
+        self.header.erase();
+        self.header.putstr_yx(Some(0), Some(0), text)?;
+        Ok(())
+    }
+
+    /// Appends a line to the body, scrolling it if it's full.
+    pub fn append_line(&mut self, line: &str) -> NcResult<()> {
+        if !self.empty {
+            self.body.putln()?;
+        }
+        self.empty = false;
+        if !line.is_empty() {
+            self.body.putstr(line)?;
+        }
+        Ok(())
+    }
+
+    /// Scrolls the body `n` lines up, returning the number of lines scrolled.
+    pub fn scroll(&mut self, n: u32) -> NcResult<u32> {
+        self.body.scrollup(n)
+    }
+
+    /// Lays out the header and the body again after resizing the parent,
+    /// keeping the rows of the header.
+    ///
+    /// # Errors
+    /// If the parent doesn't have any rows left for the body.
+    pub fn resize(&mut self) -> NcResult<()> {
+        let (rows, cols) = self.parent.dim_yx();
+        if self.header_rows >= rows {
+            return Err(NcError::new_msg(&format!(
+                "NcScrollRegion.resize(): no rows left for the body in a {}x{} plane",
+                rows, cols
+            )));
+        }
+        self.header.resize_simple(self.header_rows, cols)?;
+        self.body.resize_simple(rows - self.header_rows, cols)?;
+        self.body.move_yx(self.header_rows as i32, 0)
+    }
+}
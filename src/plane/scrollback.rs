@@ -0,0 +1,195 @@
+//! `NcScrollback`
+
+use super::helpers::truncate_to_width;
+use crate::{NcPlane, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// A scrollable log view over a scrolling [`NcPlane`], that retains the
+/// lines scrolled off its top in a ring buffer.
+///
+/// Lines are appended with [`push`]. While the view is at the bottom, each
+/// line is written at the next row of the plane, and once the plane is full
+/// its top line is captured into the ring buffer right before scrolling the
+/// plane with [`NcPlane.scrollup`], so appending a line only costs a scroll
+/// and a write.
+///
+/// Any window of the history can be redrawn with [`scroll_to`]. New lines
+/// don't move the view while it's scrolled back, and it follows them again
+/// once scrolled to the bottom.
+///
+/// Only the text of the lines is retained. Lines wider than the plane are
+/// truncated when drawn.
+///
+/// [`NcPlane.scrollup`]: crate::NcPlane#method.scrollup
+/// [`push`]: NcScrollback#method.push
+/// [`scroll_to`]: NcScrollback#method.scroll_to
+#[derive(Debug)]
+pub struct NcScrollback<'a> {
+    plane: &'a mut NcPlane,
+    /// The lines scrolled off the top of the plane, oldest first.
+    history: VecDeque<String>,
+    capacity: usize,
+    /// The lines in the rows of the plane, when at the bottom.
+    live: VecDeque<String>,
+    /// The line at the top of the view, if scrolled back.
+    top: Option<usize>,
+}
+
+impl<'a> NcScrollback<'a> {
+    /// New `NcScrollback` over `plane`, retaining up to `capacity` lines
+    /// scrolled off its top.
+    ///
+    /// The plane is set to scroll.
+    pub fn new(plane: &'a mut NcPlane, capacity: usize) -> Self {
+        plane.set_scrolling(true);
+        Self {
+            plane,
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+            live: VecDeque::new(),
+            top: None,
+        }
+    }
+
+    /// Returns a reference to the plane.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the number of retained lines, including the ones in the plane.
+    pub fn len(&self) -> usize {
+        self.history.len() + self.live.len()
+    }
+
+    /// Returns `true` if there are no retained lines.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index of the line shown at the top of the plane.
+    pub fn top_line(&self) -> usize {
+        self.top.unwrap_or(self.history.len())
+    }
+
+    /// Returns the retained line at `index`, if any.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.history
+            .iter()
+            .chain(self.live.iter())
+            .nth(index)
+            .map(|l| l.as_str())
+    }
+
+    /// Appends a line, scrolling the plane up if it's full, and discarding
+    /// the oldest line scrolled off if the capacity is reached.
+    ///
+    /// A `text` with newlines is appended as multiple lines.
+    ///
+    /// If the view is scrolled back, the plane is left untouched, unless the
+    /// lines shown are discarded.
+    ///
+    /// # Errors
+    /// If a line can't be drawn, e.g. because of other control characters.
+    pub fn push(&mut self, text: &str) -> NcResult<()> {
+        let rows = self.plane.dim_y() as usize;
+        let mut stale = false;
+        for line in text.split('\n') {
+            if self.live.len() >= rows {
+                if let Some(top) = self.live.pop_front() {
+                    stale |= self.capture(top);
+                }
+                if self.top.is_none() {
+                    self.plane.scrollup(1)?;
+                }
+            }
+            if self.top.is_none() {
+                self.draw_line(self.live.len() as u32, line)?;
+            }
+            self.live.push_back(line.to_string());
+        }
+        if stale {
+            self.redraw()?;
+        }
+        Ok(())
+    }
+
+    /// Redraws the plane with the line at index `line` at the top.
+    ///
+    /// The index is clamped so that the view never goes past the last line,
+    /// and the view follows the new lines again if it reaches the bottom.
+    pub fn scroll_to(&mut self, line: usize) -> NcResult<()> {
+        let max_top = self.max_top();
+        let top = line.min(max_top);
+        self.top = if top == max_top { None } else { Some(top) };
+        self.redraw()
+    }
+
+    /// Redraws the plane showing the last lines.
+    pub fn scroll_to_bottom(&mut self) -> NcResult<()> {
+        self.scroll_to(usize::MAX)
+    }
+
+    /// Redraws the current window of lines.
+    pub fn redraw(&mut self) -> NcResult<()> {
+        self.plane.erase();
+        let rows = self.plane.dim_y() as usize;
+        let top = self.top_line();
+        let (history, live) = (&self.history, &self.live);
+        for (y, line) in history
+            .iter()
+            .chain(live.iter())
+            .skip(top)
+            .take(rows)
+            .enumerate()
+        {
+            let (line, _) = truncate_to_width(line, self.plane.dim_x());
+            self.plane.putstr_yx(Some(y as u32), Some(0), line)?;
+        }
+        self.plane.cursor_move_yx(0, 0)
+    }
+
+    /// Captures a `line` scrolled off the top of the plane, discarding the
+    /// oldest one if the capacity is reached.
+    ///
+    /// Returns `true` if a line shown in a scrolled back view was discarded.
+    fn capture(&mut self, line: String) -> bool {
+        let discarded = if self.history.len() < self.capacity {
+            self.history.push_back(line);
+            false
+        } else {
+            if self.capacity > 0 {
+                self.history.pop_front();
+                self.history.push_back(line);
+            }
+            true
+        };
+        match (&mut self.top, discarded) {
+            (Some(0), true) => true,
+            (Some(top), true) => {
+                *top -= 1;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Draws a `line` at the `row` of the plane, replacing its contents.
+    fn draw_line(&mut self, row: u32, line: &str) -> NcResult<()> {
+        self.plane.erase_region(Some(row), Some(0), 1, 0)?;
+        let (line, _) = truncate_to_width(line, self.plane.dim_x());
+        self.plane.putstr_yx(Some(row), Some(0), line)?;
+        Ok(())
+    }
+
+    /// The top line index that shows the last lines at the bottom.
+    fn max_top(&self) -> usize {
+        self.len().saturating_sub(self.plane.dim_y() as usize)
+    }
+}
@@ -0,0 +1,106 @@
+//! `NcSprixel`
+
+use crate::{NcBlitter, NcError, NcPlane, NcResult, NcVisual, NcVisualOptions};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// Animates an [`NcPlane`] by swapping between pre-loaded RGBA frames,
+/// using the [`Pixel`] blitter.
+///
+/// Each frame is kept as an [`NcVisual`], so switching frames only needs
+/// to re-blit it, instead of decoding the image again.
+///
+/// The frames are destroyed when the `NcSprixel` is dropped.
+///
+/// [`Pixel`]: NcBlitter::Pixel
+#[derive(Debug)]
+pub struct NcSprixel<'a> {
+    plane: &'a mut NcPlane,
+    frames: Vec<&'a mut NcVisual>,
+    current: Option<usize>,
+}
+
+impl<'a> NcSprixel<'a> {
+    /// New `NcSprixel` blitting its frames into the provided `plane`.
+    pub fn new(plane: &'a mut NcPlane) -> Self {
+        Self { plane, frames: Vec::new(), current: None }
+    }
+
+    /// Returns a mutable reference to the wrapped plane.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the number of loaded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if there are no frames loaded.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the index of the frame currently blitted, if any.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Loads a new frame from tightly packed `rgba` bytes, laid out as
+    /// `len_y` rows of `len_x` pixels, and returns its index.
+    ///
+    /// The bytes are copied, so the buffer can be reused afterwards.
+    pub fn add_frame(&mut self, rgba: &[u8], len_y: u32, len_x: u32) -> NcResult<usize> {
+        let size = len_x
+            .checked_mul(4)
+            .and_then(|rowstride| (rowstride as usize).checked_mul(len_y as usize));
+        if size.map_or(true, |size| rgba.len() < size) {
+            return Err(NcError::new_msg(&format!(
+                "NcSprixel.add_frame(): {0} bytes are not enough for {1}x{2} pixels",
+                rgba.len(),
+                len_y,
+                len_x
+            )));
+        }
+        // the rowstride can't overflow, since the size didn't
+        let visual = NcVisual::from_rgba(rgba, len_y, len_x * 4, len_x)?;
+        self.frames.push(visual);
+        Ok(self.frames.len() - 1)
+    }
+
+    /// Blits the frame at `index` into the plane.
+    ///
+    /// Does nothing if the frame is already the current one.
+    ///
+    /// # Errors
+    /// If there's no frame at `index`, or if the blitting fails.
+    pub fn set_frame(&mut self, index: usize) -> NcResult<()> {
+        if self.current == Some(index) {
+            return Ok(());
+        }
+        let len = self.frames.len();
+        let visual = self.frames.get_mut(index).ok_or_else(|| {
+            NcError::new_msg(&format!(
+                "NcSprixel.set_frame({0}): there are only {1} frames",
+                index, len
+            ))
+        })?;
+        let nc = unsafe { self.plane.notcurses()? };
+        let options = NcVisualOptions::builder()
+            .plane(self.plane)
+            .blitter(NcBlitter::Pixel)
+            .build();
+        unsafe { visual.blit(nc, Some(&options))? };
+        self.current = Some(index);
+        Ok(())
+    }
+}
+
+impl Drop for NcSprixel<'_> {
+    fn drop(&mut self) {
+        for visual in self.frames.drain(..) {
+            visual.destroy();
+        }
+    }
+}
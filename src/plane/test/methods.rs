@@ -1,4 +1,2224 @@
 //! Test `NcPlane` methods and associated functions.
 
-// use crate::NcPlane;
-// use serial_test::serial;
+use crate::{
+    c_api::notcurses_init_test, NcAlign, NcAlpha, NcBlitter, NcBoxEdge, NcBoxGlyphs, NcBoxMask,
+    NcCell, NcChannel, NcChannelOrder, NcChannels, NcControlPolicy, NcDecodedCell, NcMergeMode,
+    NcPlane, NcPlaneOptions, NcRenderTracker, NcResult, NcRgbaExporter, NcRgbaImage,
+    NcScrollRegion, NcScrollback, NcSprixel, NcStyle, NcTextFlow, NcViewport,
+};
+use serial_test::serial;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec, vec::Vec};
+
+#[test]
+#[serial]
+fn putstr_with_overflow() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let mut pages = 0;
+    plane.putstr_with_overflow("0\n1\n2\n3\n4\n5\n6\n7", |p| {
+        pages += 1;
+        p.erase();
+        Ok(())
+    })?;
+    assert_eq![2, pages];
+    assert_eq!["6", plane.contents(Some(0), Some(0), Some(1), Some(1))?];
+    assert_eq!["7", plane.contents(Some(1), Some(0), Some(1), Some(1))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_fg_hex() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    plane.set_fg_hex("#00ff00")?;
+    assert_eq![(0, 255, 0), plane.fg_rgb().into()];
+    assert![plane.set_bg_hex("#00ff0").is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn supports_pixels() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let geom = plane.pixel_geom();
+    let has_bitmap = geom.max_bitmap_y != 0 && geom.max_bitmap_x != 0;
+    assert_eq![has_bitmap, plane.supports_pixels()];
+    assert_eq![has_bitmap, geom.supports_pixels()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn swap_contents() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let p1 = NcPlane::new_pile_sized(nc, 0, 0, 2, 3)?;
+    let p2 = NcPlane::new_pile_sized(nc, 0, 0, 2, 3)?;
+    let p3 = NcPlane::new_pile_sized(nc, 0, 0, 3, 3)?;
+
+    p1.putstr_yx(Some(0), Some(0), "abc")?;
+    p2.set_fg_rgb(0x112233);
+    p2.putstr_yx(Some(0), Some(0), "xyz")?;
+    p2.putstr_yx(Some(1), Some(0), "uvw")?;
+
+    // an empty cell with a background and a style
+    let channels = NcChannels::from_rgb(0x445566, 0x778899);
+    let mut empty = NcCell::new();
+    empty.channels = channels.0;
+    empty.stylemask = NcStyle::Bold.0;
+    p1.putc_yx(1, 2, &empty)?;
+    p1.putstr_yx(Some(1), Some(0), "de")?;
+
+    p1.swap_contents(p2)?;
+    assert_eq!["xyzuvw", p1.contents(Some(0), Some(0), None, None)?];
+    assert_eq!["abcde", p2.contents(Some(0), Some(0), None, None)?];
+    let cell = p2.read_cell(1, 2)?;
+    assert_eq![
+        ("", NcStyle::Bold, channels),
+        (cell.egc.as_str(), cell.style, cell.channels)
+    ];
+    assert_eq![NcStyle::None, p1.read_cell(1, 2)?.style];
+    assert![p1.swap_contents(p3).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn fill_char() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 4)?;
+
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+    assert_eq![12, plane.fill_char('░', NcStyle::Bold, channels)?];
+    assert_eq![(0, 0), plane.cursor_yx()];
+
+    for (y, x) in [(0, 0), (1, 2), (2, 3)] {
+        let cell = plane.read_cell(y, x)?;
+        assert_eq!["░", cell.egc];
+        assert_eq![NcStyle::Bold, cell.style];
+        assert_eq![channels, cell.channels];
+    }
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn box_sized_interior() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+
+    let interior = plane.box_sized_interior("┌┐└┘─│", NcStyle::None, 0, 5, 5, NcBoxMask::None)?;
+    assert_eq![((1, 1), (3, 3)), interior];
+    assert_eq!["┌───┐", plane.contents(Some(0), Some(0), Some(1), Some(5))?];
+    assert_eq!["│", plane.contents(Some(2), Some(0), Some(1), Some(1))?];
+    assert_eq!["└───┘", plane.contents(Some(4), Some(0), Some(1), Some(5))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_lines() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 10)?;
+
+    assert_eq![3, plane.putstr_lines(1, 2, "one\ntwo\nthree")?];
+    assert_eq!["one", plane.contents(Some(1), Some(2), Some(1), Some(3))?];
+    assert_eq!["two", plane.contents(Some(2), Some(2), Some(1), Some(3))?];
+    assert_eq!["three", plane.contents(Some(3), Some(2), Some(1), Some(5))?];
+    assert![plane.putstr_lines(3, 0, "a\nb\nc").is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn replace_channels() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    plane.set_channels(NcChannels::from_rgb(0x112233, 0x445566));
+    let prior = plane.channels();
+    let new = NcChannels::from_rgb(0x778899, 0xAABBCC);
+    assert_eq![prior, plane.replace_channels(new)];
+    assert_eq![new, plane.channels()];
+    assert_eq![new, plane.replace_channels(prior)];
+    assert_eq![prior, plane.channels()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn resize_marginalized_realign() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 40)?;
+    let options = NcPlaneOptions::builder()
+        .margins(2, 4)
+        .align(NcAlign::Top, NcAlign::Left)
+        .build();
+    let child = NcPlane::new_child(parent, &options)?;
+
+    parent.resize_simple(30, 60)?;
+    child.resize_marginalized_realign()?;
+    assert_eq![(28, 56), child.dim_yx()];
+    assert_eq![(0, 0), child.yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn cursor_in_bounds() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    assert![plane.cursor_in_bounds()];
+    plane.cursor_move_yx(2, 9)?;
+    assert![plane.cursor_in_bounds()];
+    plane.putchar('x')?;
+    assert![!plane.cursor_in_bounds()];
+    assert![plane.putchar('y').is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn mergedown_mode() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let dst = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+    let src = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    let bg = NcChannels::from_rgb(0xFFFFFF, 0x0000AA);
+    src.set_bg_alpha(NcAlpha::Transparent)?;
+    src.putstr_yx(Some(0), Some(0), "x")?;
+
+    // blending keeps the background contents where the source is transparent
+    dst.fill_char('.', NcStyle::None, bg)?;
+    dst.mergedown_mode(
+        src,
+        Some(0),
+        Some(0),
+        None,
+        None,
+        Some(0),
+        Some(0),
+        NcMergeMode::Blend,
+    )?;
+    assert_eq!["x...", dst.contents(Some(0), Some(0), Some(1), None)?];
+
+    // replacing discards the previous contents, even where the source is transparent
+    dst.fill_char('.', NcStyle::None, bg)?;
+    dst.mergedown_mode(
+        src,
+        Some(0),
+        Some(0),
+        Some(1),
+        Some(2),
+        Some(0),
+        Some(0),
+        NcMergeMode::Replace,
+    )?;
+    assert_eq![
+        "x",
+        dst.contents(Some(0), Some(0), Some(1), Some(2))?.trim_end()
+    ];
+    assert_eq!["..", dst.contents(Some(0), Some(2), Some(1), Some(2))?];
+    assert_eq!["....", dst.contents(Some(1), Some(0), Some(1), None)?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn rgba_exporter() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+    let mut exporter = NcRgbaExporter::new(NcBlitter::Ascii);
+
+    plane.fill_char(' ', NcStyle::None, NcChannels::from_rgb(0, 0xFF0000))?;
+    let (pixels, pxy, pxx) = exporter.export(plane, Some(0), Some(0), None, None)?;
+    assert_eq![(2, 4), (pxy, pxx)];
+    assert_eq![8, pixels.len()];
+    let (ptr, capacity) = (pixels.as_ptr() as usize, exporter.capacity());
+
+    plane.fill_char(' ', NcStyle::None, NcChannels::from_rgb(0, 0x00FF00))?;
+    let (pixels, _, _) = exporter.export(plane, Some(0), Some(0), Some(1), Some(2))?;
+    assert_eq![2, pixels.len()];
+    assert_eq![ptr, pixels.as_ptr() as usize];
+    assert_eq![capacity, exporter.capacity()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn to_char_grid() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 3)?;
+
+    plane.putstr_yx(Some(0), Some(0), "#.#")?;
+    plane.putstr_yx(Some(1), Some(1), "@")?;
+    plane.putstr_yx(Some(2), Some(0), "#.#")?;
+    assert_eq![
+        vec![
+            vec!['#', '.', '#'],
+            vec![' ', '@', ' '],
+            vec!['#', '.', '#']
+        ],
+        plane.to_char_grid(Some(0), Some(0), None, None)?
+    ];
+    assert_eq![
+        vec![vec!['@', ' ']],
+        plane.to_char_grid(Some(1), Some(1), Some(1), Some(2))?
+    ];
+    assert![plane.to_char_grid(Some(1), Some(1), Some(3), None).is_err()];
+    assert![plane
+        .to_char_grid(Some(1), Some(1), Some(u32::MAX), Some(u32::MAX))
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn put_char_cell() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+    plane.set_channels(channels);
+    plane.set_styles(NcStyle::Italic);
+    assert_eq![1, plane.put_char_cell('ñ')?];
+    assert_eq![(0, 1), plane.cursor_yx()];
+
+    let cell = plane.read_cell(0, 0)?;
+    assert_eq!["ñ", cell.egc];
+    assert_eq![NcStyle::Italic, cell.style];
+    assert_eq![channels, cell.channels];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn resize_around() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 5, 5, 10, 10)?;
+
+    plane.putstr_yx(Some(4), Some(6), "X")?;
+    let (abs_y, abs_x) = plane.abs_yx();
+
+    // zooming out doubles the focus coordinates
+    plane.resize_around(4, 6, 20, 20)?;
+    assert_eq![(20, 20), plane.dim_yx()];
+    assert_eq!["X", plane.contents(Some(8), Some(12), Some(1), Some(1))?];
+    let (new_abs_y, new_abs_x) = plane.abs_yx();
+    assert_eq![(abs_y + 4, abs_x + 6), (new_abs_y + 8, new_abs_x + 12)];
+
+    // zooming in halves them back
+    plane.resize_around(8, 12, 10, 10)?;
+    assert_eq!["X", plane.contents(Some(4), Some(6), Some(1), Some(1))?];
+    assert_eq![(abs_y, abs_x), plane.abs_yx()];
+
+    assert![plane.resize_around(10, 0, 5, 5).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn gradient2x1_debug() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 8)?;
+
+    let ul = NcChannel::from_rgb(0xFF0000);
+    let ur = NcChannel::from_rgb(0x00FF00);
+    let ll = NcChannel::from_rgb(0x0000FF);
+    let lr = NcChannel::from_rgb(0xFFFFFF);
+    let (filled, corners) =
+        plane.gradient2x1_debug(Some(0), Some(0), None, None, ul, ur, ll, lr)?;
+    assert_eq![32, filled];
+    assert_eq![[ul, ur, ll, lr], corners];
+    assert![plane
+        .gradient2x1_debug(Some(2), Some(0), Some(u32::MAX), None, ul, ur, ll, lr)
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_normalized() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 20)?;
+    let text = "ab\rc\td";
+
+    assert_eq![4, plane.putstr_normalized(text, NcControlPolicy::Skip)?];
+    assert_eq!["abcd", plane.contents(Some(0), Some(0), Some(1), Some(4))?];
+
+    plane.erase();
+    assert_eq![6, plane.putstr_normalized(text, NcControlPolicy::Symbol)?];
+    assert_eq![
+        "ab␍c␉d",
+        plane.contents(Some(0), Some(0), Some(1), Some(6))?
+    ];
+
+    plane.erase();
+    assert_eq![9, plane.putstr_normalized(text, NcControlPolicy::Expand)?];
+    assert_eq![(0, 9), plane.cursor_yx()];
+    assert_eq![
+        "c       d",
+        plane.contents(Some(0), Some(0), Some(1), Some(9))?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn read_cell() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10)?;
+
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+    plane.set_channels(channels);
+    plane.set_styles(NcStyle::Bold | NcStyle::Underline);
+    plane.putstr_yx(Some(0), Some(0), "漢")?;
+
+    let cell = plane.read_cell(0, 0)?;
+    assert_eq![
+        NcDecodedCell {
+            egc: "漢".into(),
+            style: NcStyle::Bold | NcStyle::Underline,
+            channels,
+            width: 2,
+        },
+        cell
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "libc")]
+fn flash() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10)?;
+
+    plane.putstr("splash")?;
+    let zero = crate::NcTime::new(0, 0);
+    plane.flash(&zero, &zero, &zero, None)?;
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn wrapped_height() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+
+    // plain text
+    assert_eq![0, NcPlane::wrapped_height("", 10)];
+    assert_eq![1, NcPlane::wrapped_height("hello world", 11)];
+    assert_eq![2, NcPlane::wrapped_height("hello world", 10)];
+    assert_eq![3, NcPlane::wrapped_height("hello\n\nworld", 10)];
+
+    // wide glyphs
+    assert_eq![1, NcPlane::wrapped_height("漢字 漢字", 9)];
+    assert_eq![2, NcPlane::wrapped_height("漢字 漢字", 8)];
+    assert_eq![2, NcPlane::wrapped_height("漢字漢", 5)];
+
+    // over-long words
+    assert_eq![3, NcPlane::wrapped_height("abcdefghij", 4)];
+    assert_eq![3, NcPlane::wrapped_height("ab cdefghij", 4)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn box_around() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let options = NcPlaneOptions::new(3, 1, 4, 6);
+    let child = NcPlane::new_child(parent, &options)?;
+
+    parent.box_around(child, "┌┐└┘─│", NcStyle::None, 0, NcBoxMask::None)?;
+    assert_eq!["┌", parent.contents(Some(2), Some(0), Some(1), Some(1))?];
+    assert_eq!["┐", parent.contents(Some(2), Some(7), Some(1), Some(1))?];
+    assert_eq!["└", parent.contents(Some(7), Some(0), Some(1), Some(1))?];
+    assert_eq!["┘", parent.contents(Some(7), Some(7), Some(1), Some(1))?];
+
+    child.move_yx(0, 1)?;
+    assert![parent
+        .box_around(child, "┌┐└┘─│", NcStyle::None, 0, NcBoxMask::None)
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn clear() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 4)?;
+
+    plane.fill_char('#', NcStyle::None, 0)?;
+    assert_eq![4, plane.clear(Some(((1, 1), (2, 2))))?];
+    assert_eq!["####", plane.contents(Some(0), Some(0), Some(1), None)?];
+    assert_eq!["#", plane.contents(Some(1), Some(0), Some(1), Some(1))?];
+    assert_eq!["#", plane.contents(Some(2), Some(3), Some(1), Some(1))?];
+    assert_eq!["", plane.contents(Some(1), Some(1), Some(2), Some(2))?];
+    assert![plane.clear(Some(((2, 2), (2, 2)))).is_err()];
+
+    assert_eq![12, plane.clear(None)?];
+    assert_eq!["", plane.contents(Some(0), Some(0), None, None)?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn checked_resize() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+
+    plane.cursor_move_yx(9, 9)?;
+    assert![plane.checked_resize(5, 5, false).is_err()];
+    assert_eq![(10, 10), plane.dim_yx()];
+
+    plane.checked_resize(5, 5, true)?;
+    assert_eq![(5, 5), plane.dim_yx()];
+    assert_eq![(4, 4), plane.cursor_yx()];
+
+    plane.checked_resize(8, 8, false)?;
+    assert_eq![(4, 4), plane.cursor_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn blend_channels_over() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    plane.fill_char('x', NcStyle::None, NcChannels::from_rgb(0x000000, 0x0000FF))?;
+    let white = NcChannels::from_rgb(0xFFFFFF, 0xFFFFFF);
+    plane.blend_channels_over(0, 0, white, NcAlpha::Blend)?;
+    plane.blend_channels_over(0, 1, white, NcAlpha::Transparent)?;
+
+    let blended = plane.read_cell(0, 0)?;
+    assert_eq!["x", blended.egc];
+    assert_eq![(0x7F, 0x7F, 0x7F), blended.channels.fchannel().rgb8()];
+    assert_eq![(0x7F, 0x7F, 0xFF), blended.channels.bchannel().rgb8()];
+    assert_eq![
+        (0, 0, 0xFF),
+        plane.read_cell(0, 1)?.channels.bchannel().rgb8()
+    ];
+    assert![plane
+        .blend_channels_over(0, 0, white, NcAlpha::HighContrast)
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_styled() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10)?;
+
+    let vowel = (NcStyle::Bold, NcChannels::from_rgb(0xFF0000, 0));
+    let other = (NcStyle::None, NcChannels::from_rgb(0xFFFFFF, 0));
+    plane.set_styles(NcStyle::Italic);
+
+    let cols = plane.putstr_styled(
+        "hello",
+        |_, c| if "aeiou".contains(c) { vowel } else { other },
+    )?;
+    assert_eq![5, cols];
+    assert_eq![NcStyle::Italic, plane.styles()];
+
+    let (h, e) = (plane.read_cell(0, 0)?, plane.read_cell(0, 1)?);
+    assert_eq![
+        ("h", other.0, other.1),
+        (h.egc.as_str(), h.style, h.channels)
+    ];
+    assert_eq![
+        ("e", vowel.0, vowel.1),
+        (e.egc.as_str(), e.style, e.channels)
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn find() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 20)?;
+
+    plane.putstr_yx(Some(1), Some(1), "hello world hello")?;
+    assert_eq![vec![(1, 1), (1, 13)], plane.find("hello", None)?];
+    assert_eq![vec![(1, 13)], plane.find("hello", Some(((0, 5), (3, 15))))?];
+    assert_eq![vec![(1, 5)], plane.find("o w", None)?];
+    assert![plane.find("hello", Some(((1, 0), (3, 20)))).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn blit_grid() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 5)?;
+
+    let (x, o) = (
+        NcChannels::from_rgb(0xFF0000, 0),
+        NcChannels::from_rgb(0x0000FF, 0),
+    );
+    let grid: [&[_]; 3] = [
+        &[
+            ('X', NcStyle::Bold, x),
+            ('O', NcStyle::None, o),
+            ('X', NcStyle::Bold, x),
+        ],
+        &[
+            ('.', NcStyle::None, 0.into()),
+            ('X', NcStyle::Bold, x),
+            ('.', NcStyle::None, 0.into()),
+        ],
+        &[
+            ('O', NcStyle::None, o),
+            ('.', NcStyle::None, 0.into()),
+            ('O', NcStyle::None, o),
+        ],
+    ];
+    plane.blit_grid(1, 2, &grid)?;
+    assert_eq![(0, 0), plane.cursor_yx()];
+
+    assert_eq!["XOX", plane.contents(Some(1), Some(2), Some(1), Some(3))?];
+    assert_eq![".X.", plane.contents(Some(2), Some(2), Some(1), Some(3))?];
+    assert_eq!["O.O", plane.contents(Some(3), Some(2), Some(1), Some(3))?];
+    let center = plane.read_cell(2, 3)?;
+    assert_eq![(NcStyle::Bold, x), (center.style, center.channels)];
+
+    assert![plane.blit_grid(3, 3, &grid).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn scrollback() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 10)?;
+    let mut log = NcScrollback::new(plane, 100);
+
+    for i in 0..20 {
+        log.push(&format!["line {}", i])?;
+    }
+    assert_eq![20, log.len()];
+    assert_eq![15, log.top_line()];
+    assert_eq![
+        "line 15",
+        log.plane().contents(Some(0), Some(0), Some(1), Some(7))?
+    ];
+    assert_eq![
+        "line 19",
+        log.plane().contents(Some(4), Some(0), Some(1), Some(7))?
+    ];
+
+    log.scroll_to(3)?;
+    assert_eq![
+        "line 3",
+        log.plane().contents(Some(0), Some(0), Some(1), Some(6))?
+    ];
+    assert_eq![
+        "line 7",
+        log.plane().contents(Some(4), Some(0), Some(1), Some(6))?
+    ];
+
+    // new lines don't move the view when scrolled back
+    log.push("line 20")?;
+    assert_eq![3, log.top_line()];
+
+    log.scroll_to(100)?;
+    assert_eq![16, log.top_line()];
+    assert_eq![
+        "line 20",
+        log.plane().contents(Some(4), Some(0), Some(1), Some(7))?
+    ];
+
+    // long lines are truncated, and newlines split lines
+    log.push("a line wider than the plane\nlast")?;
+    assert_eq![23, log.len()];
+    assert_eq![
+        "a line wid",
+        log.plane().contents(Some(3), Some(0), Some(1), None)?
+    ];
+    assert_eq![Some("a line wider than the plane"), log.line(21)];
+    assert_eq![Some("last"), log.line(22)];
+
+    // only the last `capacity` lines scrolled off are retained
+    let small = NcPlane::new_pile_sized(nc, 0, 0, 2, 10)?;
+    let mut log = NcScrollback::new(small, 2);
+    log.push("0\n1\n2\n3\n4\n5")?;
+    assert_eq![4, log.len()];
+    assert_eq![Some("2"), log.line(0)];
+    assert_eq![
+        "4",
+        log.plane().contents(Some(0), Some(0), Some(1), Some(1))?
+    ];
+
+    // discarding the lines shown by a scrolled back view redraws it
+    log.scroll_to(0)?;
+    log.push("6")?;
+    assert_eq![0, log.top_line()];
+    assert_eq![
+        "3",
+        log.plane().contents(Some(0), Some(0), Some(1), Some(1))?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn thumbnail() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let source = NcPlane::new_pile_sized(nc, 0, 0, 8, 16)?;
+    let thumb = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    // a checkerboard of red and blue cells
+    for y in 0..8 {
+        for x in 0..16 {
+            let bg = if (y + x) % 2 == 0 { 0xFF0000 } else { 0x0000FF };
+            source.set_bg_rgb(bg);
+            source.putstr_yx(Some(y), Some(x), " ")?;
+        }
+    }
+    source.thumbnail(thumb, NcBlitter::Ascii)?;
+
+    let cell = thumb.read_cell(0, 0)?;
+    assert![!cell.channels.bchannel().default_p()];
+    assert_ne![0, cell.channels.bchannel().rgb().0];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_name() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    plane.set_name("first")?;
+    assert_eq![Some("first".into()), plane.name()];
+    plane.set_name("second")?;
+    assert_eq![Some("second".into()), plane.name()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn writer_split_codepoint() -> NcResult<()> {
+    use std::io::Write;
+
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 8)?;
+
+    let bytes = "añb".as_bytes();
+    let mut writer = plane.writer();
+    assert_eq![2, writer.write(&bytes[..2]).unwrap()];
+    assert_eq![1, writer.pending().len()];
+    assert_eq![2, writer.write(&bytes[2..]).unwrap()];
+    assert![writer.pending().is_empty()];
+    writer.flush().unwrap();
+
+    assert_eq![
+        vec![vec!['a', 'ñ', 'b']],
+        plane.to_char_grid(Some(0), Some(0), Some(1), Some(3))?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn writer_partial_writes() -> NcResult<()> {
+    use std::io::{ErrorKind, Write};
+
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 4)?;
+    let mut writer = plane.writer();
+
+    // a NUL byte stops the write, and is rejected when it comes first
+    assert_eq![1, writer.write(b"a\0b").unwrap()];
+    assert_eq![
+        ErrorKind::InvalidInput,
+        writer.write(b"\0b").unwrap_err().kind()
+    ];
+
+    // only the bytes that fit are reported as written
+    assert_eq![3, writer.write(b"bcdef").unwrap()];
+    assert![writer.write(b"ef").is_err()];
+
+    // the pending bytes survive a failed write
+    let bytes = "ñ".as_bytes();
+    assert_eq![1, writer.write(&bytes[..1]).unwrap()];
+    assert![writer.write(&bytes[1..]).is_err()];
+    assert_eq![&bytes[..1], writer.pending()];
+
+    assert_eq![
+        vec![vec!['a', 'b', 'c', 'd']],
+        plane.to_char_grid(Some(0), Some(0), Some(1), Some(4))?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn panel() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 6, 10)?;
+
+    let origin = plane.panel(1, 1, 4, 8, "┌┐└┘─│", 0, Some("title"))?;
+    assert_eq![(2, 2), origin];
+    assert_eq![(2, 2), plane.cursor_yx()];
+    assert_eq![
+        vec![vec!['┌', 't', 'i', 't', 'l', 'e', '─', '┐']],
+        plane.to_char_grid(Some(1), Some(1), Some(1), Some(8))?
+    ];
+
+    // a title wider than the interior is truncated
+    plane.panel(1, 1, 4, 5, "┌┐└┘─│", 0, Some("title"))?;
+    assert_eq![
+        vec![vec!['┌', 't', 'i', 't', '┐']],
+        plane.to_char_grid(Some(1), Some(1), Some(1), Some(5))?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn translate2() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let options = NcPlaneOptions::new(3, 5, 4, 6);
+    let child = NcPlane::new_child(parent, &options)?;
+
+    assert_eq![(4, 7), child.translate2(parent, 1, 2)];
+    assert_eq![(-2, -4), parent.translate2(child, 1, 1)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn gradient_fn() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 5)?;
+    plane.fill_char('x', NcStyle::None, 0)?;
+
+    // bright at the center, fading towards the corners
+    let radial = |ny: f32, nx: f32| {
+        let d2 = (ny - 0.5) * (ny - 0.5) + (nx - 0.5) * (nx - 0.5);
+        let v = (255.0 * (1.0 - 2.0 * d2)) as u32;
+        NcChannels::from_rgb(0, v << 16 | v << 8 | v)
+    };
+    assert_eq![25, plane.gradient_fn(None, radial)?];
+
+    let center = plane.read_cell(2, 2)?.channels.bchannel().rgb8();
+    let corner = plane.read_cell(0, 0)?.channels.bchannel().rgb8();
+    assert_eq![(0xFF, 0xFF, 0xFF), center];
+    assert_eq![(0, 0, 0), corner];
+
+    assert![plane.gradient_fn(Some(((4, 4), (2, 2))), radial).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn translate_abs2() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let options = NcPlaneOptions::new(3, 5, 4, 6);
+    let child = NcPlane::new_child(parent, &options)?;
+
+    assert_eq![(1, 2, true), child.translate_abs2(4, 7)];
+    assert_eq![(-2, -4, false), child.translate_abs2(1, 1)];
+    assert_eq![(5, 0, false), child.translate_abs2(8, 5)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_if_changed() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+    let mut tracker = NcRenderTracker::new();
+
+    plane.putstr("abc")?;
+    assert![plane.render_if_changed(&mut tracker)?];
+    assert![!plane.render_if_changed(&mut tracker)?];
+
+    plane.putstr_yx(Some(1), Some(0), "d")?;
+    assert![plane.render_if_changed(&mut tracker)?];
+    assert![!plane.render_if_changed(&mut tracker)?];
+
+    // the planes' placement is part of the frame
+    let child = NcPlane::new_child(plane, &NcPlaneOptions::new(0, 0, 1, 1))?;
+    assert![plane.render_if_changed(&mut tracker)?];
+    child.move_yx(1, 1)?;
+    assert![plane.render_if_changed(&mut tracker)?];
+
+    tracker.reset();
+    assert![tracker.last_hash().is_none()];
+    assert![plane.render_if_changed(&mut tracker)?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_to_buffer() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 8)?;
+    plane.putstr("hello")?;
+
+    let mut buffer = vec![0; 1024];
+    plane.render()?;
+    plane.render_to_buffer(&mut buffer)?;
+    assert![!buffer.is_empty()];
+    let output = core::str::from_utf8(&buffer).expect("invalid UTF-8 output");
+    assert![output.contains("hello")];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn cursor_move_checked() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 10)?;
+
+    assert_eq![Ok(()), plane.cursor_move_checked(3, 9)];
+    assert_eq![(3, 9), plane.cursor_yx()];
+
+    assert_eq![Err((0, 3)), plane.cursor_move_checked(1, 12)];
+    assert_eq![Err((2, 1)), plane.cursor_move_checked(5, 10)];
+    assert_eq![(3, 9), plane.cursor_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn cells() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 3)?;
+    plane.putstr_yx(Some(0), Some(0), "a界")?;
+    plane.putstr_yx(Some(1), Some(0), "bcd")?;
+
+    let cells = plane
+        .cells()
+        .map(|item| item.map(|(y, x, cell)| (y, x, cell.egc)))
+        .collect::<NcResult<Vec<_>>>()?;
+    assert_eq![
+        vec![
+            (0, 0, "a".into()),
+            (0, 1, "界".into()),
+            (1, 0, "b".into()),
+            (1, 1, "c".into()),
+            (1, 2, "d".into()),
+        ],
+        cells
+    ];
+
+    let rows: Vec<_> = plane.rows_contents().collect();
+    assert_eq![vec!["a界", "bcd"], rows];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn puttext_scrolling() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+
+    let text = "one two three four five six seven eight nine ten eleven twelve";
+    let mut pages = 0;
+    plane.puttext_scrolling(NcAlign::Left, text, |_| {
+        pages += 1;
+        Ok(())
+    })?;
+    // 7 rows of text in a 3 rows plane
+    assert_eq![7, NcPlane::wrapped_height(text, 10)];
+    assert_eq![2, pages];
+    assert![!plane.scrolling_p()];
+    assert_eq![
+        "twelve",
+        plane.contents(Some(2), Some(0), Some(1), Some(6))?
+    ];
+
+    // the first page ends at the bottom of the plane
+    plane.erase();
+    plane.cursor_move_yx(2, 0)?;
+    let mut last_rows = Vec::new();
+    plane.puttext_scrolling(NcAlign::Left, text, |p| {
+        last_rows.push(p.contents(Some(2), Some(0), Some(1), None)?);
+        Ok(())
+    })?;
+    assert_eq![vec!["one two", "seven"], last_rows];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn box_sized_homed() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 6, 10)?;
+
+    let (mut ul, mut ur, mut ll) = (NcCell::new(), NcCell::new(), NcCell::new());
+    let (mut lr, mut hl, mut vl) = (NcCell::new(), NcCell::new(), NcCell::new());
+    NcCell::load_box(
+        plane,
+        NcStyle::None,
+        0,
+        &mut ul,
+        &mut ur,
+        &mut ll,
+        &mut lr,
+        &mut hl,
+        &mut vl,
+        "┌┐└┘─│",
+    )?;
+
+    plane.cursor_move_yx(1, 2)?;
+    let cursor = plane.box_sized_homed(&ul, &ur, &ll, &lr, &hl, &vl, 3, 4, NcBoxMask::None)?;
+    assert_eq![(1, 2), cursor];
+    assert_eq![(1, 2), plane.cursor_yx()];
+    assert_eq!["┘", plane.contents(Some(3), Some(5), Some(1), Some(1))?];
+
+    for cell in [&mut ul, &mut ur, &mut ll, &mut lr, &mut hl, &mut vl] {
+        cell.release(plane);
+    }
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn try_cursor_yx() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    assert_eq![(0, 0), plane.try_cursor_yx()?];
+    plane.cursor_move_yx(1, 3)?;
+    assert_eq![(1, 3), plane.try_cursor_yx()?];
+    assert_eq![plane.try_cursor_yx()?, plane.cursor_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn composited_contents() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 3, 10)?;
+    parent.putstr_yx(Some(1), Some(0), "0123456789")?;
+    let child = NcPlane::new_child(parent, &NcPlaneOptions::new(1, 2, 1, 5))?;
+    child.putstr("child")?;
+
+    assert_eq![
+        "0123456789",
+        parent.contents(Some(1), Some(0), Some(1), Some(10))?
+    ];
+    assert_eq![
+        "01child789",
+        parent.composited_contents(Some(((1, 0), (1, 10))))?
+    ];
+
+    // the empty cells show the planes below, and wide glyphs appear once
+    child.putstr_yx(Some(0), Some(0), "界")?;
+    child.erase_region(Some(0), Some(2), 1, 3)?;
+    assert_eq![
+        "01界456789",
+        parent.composited_contents(Some(((1, 0), (1, 10))))?
+    ];
+
+    let offscreen = NcPlane::new_child(parent, &NcPlaneOptions::new(-1, 0, 2, 2))?;
+    assert![offscreen.composited_contents(None).is_err()];
+    assert![offscreen
+        .composited_contents(Some(((1, 0), (1, 2))))
+        .is_ok()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn fill() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 6)?;
+
+    let mut cell = NcCell::from_str(plane, "#")?;
+    assert_eq![6, plane.fill(Some(1), Some(2), 2, 3, &cell)?];
+    assert_eq!["###", plane.contents(Some(2), Some(2), Some(1), Some(3))?];
+    assert_eq![
+        "",
+        plane.contents(Some(0), Some(0), Some(1), Some(6))?.trim()
+    ];
+    assert_eq![(0, 0), plane.cursor_yx()];
+
+    // 0 lengths reach the boundary
+    assert_eq![8, plane.fill(Some(2), Some(2), 0, 0, &cell)?];
+
+    assert![plane.fill(Some(4), Some(0), 1, 1, &cell).is_err()];
+    assert![plane.fill(Some(0), Some(4), 1, 3, &cell).is_err()];
+
+    cell.release(plane);
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn resize_clamped() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+
+    assert_eq![(5, 5), plane.resize_clamped((1, 1), (5, 5), (20, 20))?];
+    assert_eq![(5, 5), plane.dim_yx()];
+    assert_eq![(20, 8), plane.resize_clamped((30, 8), (5, 5), (20, 20))?];
+    assert_eq![(20, 8), plane.dim_yx()];
+    assert![plane.resize_clamped((8, 8), (5, 5), (4, 20)).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn stain_uniform() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 6)?;
+    plane.fill_char('x', NcStyle::Bold, 0)?;
+
+    let red = NcChannels::from_rgb(0xFF0000, 0x000000);
+    assert_eq![
+        4,
+        plane.stain_uniform(Some(1), Some(1), Some(2), Some(2), red)?
+    ];
+
+    let stained = plane.read_cell(2, 2)?;
+    assert_eq!["x", stained.egc];
+    assert_eq![NcStyle::Bold, stained.style];
+    assert_eq![(0xFF, 0, 0), stained.channels.fchannel().rgb8()];
+    assert_ne![red, plane.read_cell(0, 0)?.channels];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_wrapped_tracked() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 10)?;
+
+    let text = "the quick brown fox jumps\nover";
+    let (rows, breaks) = plane.putstr_wrapped_tracked(text)?;
+    assert_eq![4, rows];
+    assert_eq![vec![10, 20], breaks];
+    for offset in &breaks {
+        assert_eq![Some(' '), text[..*offset].chars().last()];
+    }
+    assert_eq!["brown", plane.contents(Some(1), Some(0), Some(1), Some(5))?];
+    assert_eq!["jumps", plane.contents(Some(2), Some(0), Some(1), Some(5))?];
+    assert_eq!["over", plane.contents(Some(3), Some(0), Some(1), Some(4))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_base_char() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 2)?;
+
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+    plane.set_base_char('·', channels)?;
+    let base = plane.base()?;
+    assert_eq![channels.0, base.channels];
+    assert_eq!["·", base.egc(plane)];
+
+    plane.set_base_default()?;
+    let base = plane.base()?;
+    assert_eq![NcChannels::with_default().0, base.channels];
+    assert_eq![NcStyle::None, base.styles()];
+    assert_eq![" ", base.egc(plane)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_base_recursive() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let root = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let panel = NcPlane::new_child(root, &NcPlaneOptions::new(1, 1, 8, 18))?;
+    let child = NcPlane::new_child(panel, &NcPlaneOptions::new(1, 1, 2, 4))?;
+    let grandchild = NcPlane::new_child(child, &NcPlaneOptions::new(0, 0, 1, 1))?;
+    let other = NcPlane::new_child(root, &NcPlaneOptions::new(0, 0, 1, 1))?;
+
+    let channels = NcChannels::from_rgb(0x112233, 0x445566);
+    panel.set_base_recursive(" ", NcStyle::None, channels)?;
+    for plane in [panel, child, grandchild] {
+        assert_eq![channels.0, plane.base()?.channels];
+    }
+    assert_ne![channels.0, other.base()?.channels];
+    assert_ne![channels.0, root.base()?.channels];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn scroll_lines() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 4)?;
+    plane.putstr_yx(Some(0), Some(0), "aaaa")?;
+    plane.putstr_yx(Some(1), Some(0), "bbbb")?;
+    plane.putstr_yx(Some(2), Some(0), "cc")?;
+
+    assert![plane.scroll_lines(1).is_err()];
+    plane.set_scrolling(true);
+
+    assert_eq![1, plane.scroll_lines(-1)?];
+    assert_eq![
+        vec!["", "aaaa", "bbbb"],
+        plane.rows_contents().collect::<Vec<_>>()
+    ];
+    assert_eq![3, plane.scroll_lines(-5)?];
+    assert_eq![vec!["", "", ""], plane.rows_contents().collect::<Vec<_>>()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn scrolldown() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 4)?;
+    plane.putstr_yx(Some(0), Some(0), "aaaa")?;
+    plane.putstr_yx(Some(1), Some(0), "b界")?;
+    plane.cursor_move_yx(2, 1)?;
+
+    assert![plane.scrolldown(1).is_err()];
+    plane.set_scrolling(true);
+
+    assert_eq![2, plane.scrolldown(2)?];
+    assert_eq![
+        vec!["", "", "aaaa"],
+        plane.rows_contents().collect::<Vec<_>>()
+    ];
+    assert_eq![(2, 1), plane.cursor_yx()];
+
+    // paging back up and down
+    plane.erase();
+    plane.putstr_yx(Some(0), Some(0), "b界")?;
+    assert_eq![1, plane.scrolldown(1)?];
+    assert_eq![
+        vec!["", "b界", ""],
+        plane.rows_contents().collect::<Vec<_>>()
+    ];
+    assert_eq![1, plane.scrollup(1)?];
+    assert_eq!["b界", plane.rows_contents().next().unwrap()];
+
+    // an empty cell keeps its background and style
+    let channels = NcChannels::from_rgb(0x445566, 0x778899);
+    let mut empty = NcCell::new();
+    empty.channels = channels.0;
+    empty.stylemask = NcStyle::Italic.0;
+    plane.erase();
+    plane.putc_yx(0, 1, &empty)?;
+    assert_eq![1, plane.scroll_lines(-1)?];
+    let cell = plane.read_cell(1, 1)?;
+    assert_eq![
+        ("", NcStyle::Italic, channels),
+        (cell.egc.as_str(), cell.style, cell.channels)
+    ];
+    assert_eq![NcChannels::from(0_u64), plane.read_cell(0, 1)?.channels];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn on_resize() -> NcResult<()> {
+    #[cfg(not(feature = "std"))]
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    #[cfg(feature = "std")]
+    use std::rc::Rc;
+
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let child = NcPlane::new_child(parent, &NcPlaneOptions::new(0, 0, 5, 10))?;
+
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let sizes_cb = sizes.clone();
+    child.on_resize(move |plane, old, new| {
+        sizes_cb.borrow_mut().push((old, new));
+        let _ = plane.resize_simple(new.0 / 2, new.1 / 2);
+    })?;
+
+    parent.resize_simple(20, 40)?;
+    parent.resize_simple(16, 30)?;
+    assert_eq![
+        vec![((10, 20), (20, 40)), ((20, 40), (16, 30))],
+        *sizes.borrow()
+    ];
+    assert_eq![(8, 15), child.dim_yx()];
+
+    child.clear_on_resize();
+    parent.resize_simple(10, 20)?;
+    assert_eq![2, sizes.borrow().len()];
+
+    // a userptr set by the user is not overwritten
+    let mut value = 7_u32;
+    unsafe { crate::c_api::ncplane_set_userptr(child, &mut value as *mut u32 as *mut _) };
+    assert![child.on_resize(|_, _, _| ()).is_err()];
+    unsafe { crate::c_api::ncplane_set_userptr(child, core::ptr::null_mut()) };
+
+    // destroying the plane drops the closure
+    let captured = sizes.clone();
+    child.on_resize(move |_, _, _| captured.borrow_mut().clear())?;
+    assert_eq![2, Rc::strong_count(&sizes)];
+    child.destroy()?;
+    assert_eq![1, Rc::strong_count(&sizes)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn contents_detailed() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    let red = NcChannels::from_rgb(0xFF0000, 0);
+    plane.set_styles(NcStyle::Bold);
+    plane.set_channels(red);
+    plane.putstr_yx(Some(0), Some(0), "a界")?;
+
+    let grid = plane.contents_detailed(Some(0), Some(0), Some(1), Some(3))?;
+    assert_eq![1, grid.len()];
+    assert_eq![("a".into(), NcStyle::Bold, red), grid[0][0]];
+    assert_eq!["界", grid[0][1].0];
+    assert_eq!["", grid[0][2].0];
+
+    assert![plane
+        .contents_detailed(Some(1), Some(0), Some(2), None)
+        .is_err()];
+    assert![plane
+        .contents_detailed(Some(1), Some(1), Some(u32::MAX), Some(u32::MAX))
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_rtl() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 8)?;
+
+    plane.cursor_move_yx(0, 7)?;
+    assert_eq![4, plane.putstr_rtl("שלום")?];
+    assert_eq!["םולש", plane.contents(Some(0), Some(4), Some(1), Some(4))?];
+    assert_eq![(0, 3), plane.cursor_yx()];
+
+    // nothing is written when it doesn't fit
+    assert![plane.putstr_rtl("abcde").is_err()];
+    assert_eq!["", plane.contents(Some(0), Some(0), Some(1), Some(4))?];
+    assert_eq![(0, 3), plane.cursor_yx()];
+
+    // combining marks stay with their glyph, reaching the left edge
+    assert_eq![4, plane.putstr_rtl("שָׁלוֹם")?];
+    assert_eq!["םוֹלשָׁ", plane.contents(Some(0), Some(0), Some(1), Some(4))?];
+    assert_eq![(0, 0), plane.cursor_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_tracked() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 5)?;
+
+    assert_eq![(3, 4), plane.putstr_tracked("añb")?];
+
+    // only "cd" fits in the rest of the line
+    let text = "cdefg";
+    let (cols, bytes) = plane.putstr_tracked(text)?;
+    assert_eq![(2, 2), (cols, bytes)];
+    assert_eq!["efg", &text[bytes..]];
+
+    plane.cursor_move_yx(1, 0)?;
+    assert_eq![(3, 3), plane.putstr_tracked(&text[bytes..])?];
+    assert_eq![(2, 2), plane.putstr_tracked("hi")?];
+    assert![plane.putstr_tracked("x").is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_wrapped() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 10)?;
+
+    assert![plane.putstr_wrapped("a b", 5).is_err()];
+    plane.set_scrolling(true);
+
+    // the first line scrolls off
+    assert_eq![5, plane.putstr_wrapped("one two three abcdefgh", 5)?];
+    assert_eq!["two", plane.contents(Some(0), Some(0), Some(1), Some(3))?];
+    assert_eq!["three", plane.contents(Some(1), Some(0), Some(1), Some(5))?];
+    assert_eq!["abcde", plane.contents(Some(2), Some(0), Some(1), Some(5))?];
+    assert_eq!["fgh", plane.contents(Some(3), Some(0), Some(1), Some(3))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn scroll_region() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 4, 10)?;
+
+    assert![NcScrollRegion::new(parent, 4).is_err()];
+    let mut region = NcScrollRegion::new(parent, 1)?;
+    region.set_header("TITLE")?;
+    for line in ["one", "two", "three", "four", "five"] {
+        region.append_line(line)?;
+    }
+    assert_eq![
+        "TITLE",
+        region
+            .header()
+            .contents(Some(0), Some(0), Some(1), Some(5))?
+    ];
+    assert_eq![(0, 0), region.header().abs_yx()];
+    assert_eq![(1, 0), region.body().abs_yx()];
+    // "one" and "two" scrolled off the 3 rows body
+    assert_eq![
+        "three",
+        region.body().contents(Some(0), Some(0), Some(1), Some(5))?
+    ];
+
+    assert_eq![1, region.scroll(1)?];
+    assert_eq![
+        "four",
+        region.body().contents(Some(0), Some(0), Some(1), Some(4))?
+    ];
+
+    region.parent().resize_simple(6, 12)?;
+    region.resize()?;
+    assert_eq![(1, 12), region.header().dim_yx()];
+    assert_eq![(5, 12), region.body().dim_yx()];
+    assert_eq![(1, 0), region.body().abs_yx()];
+    region.destroy()?;
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn to_rgba_with_bg() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 2)?;
+
+    plane.set_bg_rgb(0xFF0000);
+    plane.putstr_yx(Some(0), Some(0), " ")?;
+    plane.set_bg_alpha(NcAlpha::Transparent)?;
+    plane.putstr_yx(Some(0), Some(1), " ")?;
+
+    let (pixels, pxdim_y, pxdim_x) = plane.to_rgba_with_bg(NcBlitter::Ascii, None, 0x00FF00)?;
+    assert_eq![pixels.len(), (pxdim_y * pxdim_x) as usize];
+    let (first, last) = (
+        pixels[0].0.to_ne_bytes(),
+        pixels[pixels.len() - 1].0.to_ne_bytes(),
+    );
+    assert_eq![[0xFF, 0, 0, 0xFF], first];
+    assert_eq![[0, 0xFF, 0, 0xFF], last];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn as_rgba_owned() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 3)?;
+    plane.set_bg_rgb(0x0000FF);
+    plane.putstr_yx(Some(1), Some(2), " ")?;
+
+    let image: NcRgbaImage = plane.as_rgba_owned(NcBlitter::Ascii, Some(0), Some(0), None, None)?;
+    assert_eq![image.pixels.len(), (image.pxdim_y * image.pxdim_x) as usize];
+    let last = image.pixel(image.pxdim_y - 1, image.pxdim_x - 1).unwrap();
+    assert_eq![[0, 0, 0xFF, 0xFF], last.0.to_ne_bytes()];
+    assert_eq![None, image.pixel(image.pxdim_y, 0)];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_box_title() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 12)?;
+    plane.ascii_box(NcStyle::None, 0, 2, 11, NcBoxMask::None)?;
+
+    plane.set_box_title("hi", NcBoxEdge::Top, NcAlign::Center, NcStyle::Bold, 0)?;
+    plane.set_box_title("abc", NcBoxEdge::Bottom, NcAlign::Right, NcStyle::None, 0)?;
+    let rows: Vec<Vec<char>> = plane.rows_contents().map(|r| r.chars().collect()).collect();
+    assert_eq![&[' ', 'h', 'i', ' '], &rows[0][4..8]];
+    assert_eq![&[' ', 'a', 'b', 'c', ' '], &rows[2][6..11]];
+    assert_eq![rows[0][0], rows[2][0]];
+
+    // truncated to the room between the corners
+    plane.set_box_title(
+        "abcdefghijkl",
+        NcBoxEdge::Top,
+        NcAlign::Left,
+        NcStyle::None,
+        0,
+    )?;
+    let top: Vec<char> = plane.rows_contents().next().unwrap().chars().collect();
+    assert_eq![" abcdefgh ".chars().collect::<Vec<_>>(), &top[1..11]];
+
+    assert![plane
+        .set_box_title("x", NcBoxEdge::Top, NcAlign::Unaligned, NcStyle::None, 0)
+        .is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn box_size_for() {
+    assert_eq![(3, 3), NcPlane::box_size_for((1, 1))];
+    assert_eq![(2, 2), NcPlane::box_size_for((0, 0))];
+    assert_eq![(1, 1), NcPlane::content_size_for((3, 3))];
+    assert_eq![(0, 0), NcPlane::content_size_for((1, 2))];
+
+    for size in [(1, 1), (4, 20), (80, 1)] {
+        assert_eq![size, NcPlane::content_size_for(NcPlane::box_size_for(size))];
+    }
+}
+
+#[test]
+#[serial]
+fn putstr_clipped() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 20)?;
+
+    // a 2x6 viewport at 1,4, with the string starting before it
+    let clip = ((1, 4), (2, 6));
+    assert_eq![6, plane.putstr_clipped(1, 2, "abcdefghijklmnop", clip)?];
+    assert_eq![
+        "cdefgh",
+        plane.contents(Some(1), Some(0), Some(1), None)?.trim()
+    ];
+
+    // rows outside the clip are not written, even if inside the plane
+    assert_eq![0, plane.putstr_clipped(0, 4, "xyz", clip)?];
+    assert_eq!["", plane.contents(Some(0), Some(0), Some(1), None)?.trim()];
+
+    // negative offsets scroll the content to the left
+    assert_eq![2, plane.putstr_clipped(2, -3, "123456789", clip)?];
+    assert_eq![
+        "89",
+        plane.contents(Some(2), Some(0), Some(1), None)?.trim()
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn blit_text_block() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 10)?;
+    plane.putstr_yx(Some(2), Some(0), "##########")?;
+
+    let art = " /\\_/\\\n( o.o )\n > ^ <\n";
+    assert_eq![
+        (3, 7),
+        plane.blit_text_block((1, 2), art, NcStyle::Bold, 0)?
+    ];
+
+    assert_eq![
+        " /\\_/\\",
+        plane.contents(Some(1), Some(2), Some(1), Some(6))?
+    ];
+    assert_eq![
+        "( o.o )",
+        plane.contents(Some(2), Some(2), Some(1), Some(7))?
+    ];
+    assert_eq![
+        " > ^ <",
+        plane.contents(Some(3), Some(2), Some(1), Some(6))?
+    ];
+    // spaces are not transparent, but the rest of the row is kept
+    assert_eq![
+        "##( o.o )#",
+        plane.contents(Some(2), Some(0), Some(1), None)?
+    ];
+    assert_eq![NcStyle::None, plane.styles()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn resize_anchored() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 20)?;
+    let plane = NcPlane::new_child(parent, &NcPlaneOptions::new(5, 5, 4, 6))?;
+    plane.putstr_yx(Some(3), Some(0), "abcdef")?;
+
+    // shrinking anchored to the bottom-right keeps the bottom-right content
+    plane.resize_anchored(2, 4, NcAlign::Right)?;
+    assert_eq![(2, 4), plane.dim_yx()];
+    assert_eq![(7, 7), plane.yx()];
+    assert_eq!["cdef", plane.contents(Some(1), Some(0), Some(1), None)?];
+
+    // growing anchored to the center
+    plane.resize_anchored(4, 8, NcAlign::Center)?;
+    assert_eq![(4, 8), plane.dim_yx()];
+    assert_eq![(6, 5), plane.yx()];
+    assert_eq!["cdef", plane.contents(Some(2), Some(2), Some(1), Some(4))?];
+
+    // growing anchored to the top-left
+    plane.resize_anchored(5, 9, NcAlign::Top)?;
+    assert_eq![(6, 5), plane.yx()];
+    assert_eq!["cdef", plane.contents(Some(2), Some(2), Some(1), Some(4))?];
+
+    assert![plane.resize_anchored(1, 1, NcAlign::Unaligned).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn with_termsize_margins() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let (term_y, term_x) = nc.term_dim_yx();
+
+    if term_y > 2 && term_x > 4 {
+        let plane = NcPlane::with_termsize_margins(nc, 1, 2, 1, 2)?;
+        assert_eq![(1, 2), plane.yx()];
+        assert_eq![(term_y - 2, term_x - 4), plane.dim_yx()];
+        plane.destroy()?;
+    }
+    assert![NcPlane::with_termsize_margins(nc, term_y, 0, 0, 0).is_err()];
+    assert![NcPlane::with_termsize_margins(nc, 0, term_x, 1, 0).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_rgb8_arr_gray() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 1)?;
+
+    plane.set_fg_rgb8_arr([0x11, 0x22, 0x33]);
+    plane.set_bg_rgb8_arr([0x44, 0x55, 0x66]);
+    assert_eq![(0x11, 0x22, 0x33), plane.fg_rgb().into()];
+    assert_eq![(0x44, 0x55, 0x66), plane.bg_rgb().into()];
+
+    plane.set_fg_rgb(0x778899);
+    assert_eq![(0x77, 0x88, 0x99), plane.fg_rgb().into()];
+
+    plane.set_fg_gray(0x80);
+    plane.set_bg_gray(0);
+    assert_eq![(0x80, 0x80, 0x80), plane.fg_rgb().into()];
+    assert_eq![(0, 0, 0), plane.bg_rgb().into()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn occupied_cells() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 6)?;
+    plane.putstr_yx(Some(0), Some(1), "界 a")?;
+    plane.putstr_yx(Some(2), Some(5), "z")?;
+
+    let cells = plane.occupied_cells().collect::<NcResult<Vec<_>>>()?;
+    assert_eq![
+        vec![(0, 1, "界".into()), (0, 4, "a".into()), (2, 5, "z".into())],
+        cells
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn contents_expanded_tabs() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 12)?;
+    assert_eq![9, plane.putstr_normalized("a\tb", NcControlPolicy::Expand)?];
+
+    // the tab is read back as the spaces filling the gap, both ways
+    let contents = plane.contents(Some(0), Some(0), Some(1), Some(9))?;
+    assert_eq!["a       b", contents];
+    let cells = plane
+        .cells()
+        .take(9)
+        .map(|item| item.map(|(_, _, cell)| cell.egc))
+        .collect::<NcResult<Vec<_>>>()?;
+    assert_eq![contents, cells.concat()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_to_ansi_string() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 4)?;
+    plane.set_fg_rgb(0xFF0000);
+    plane.putstr("red")?;
+
+    let output = plane.render_to_ansi_string()?;
+    assert![output.contains("red")];
+    // the foreground color is set with an SGR sequence before the text
+    let sgr = output.find("\x1b[38").expect("no foreground SGR sequence");
+    assert![sgr < output.find("red").unwrap()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_to_string() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 6)?;
+    plane.putstr("plain")?;
+
+    let output = plane.render_to_string()?;
+    assert![output.contains("plain")];
+    assert![!output.contains('\u{FFFD}')];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn box_styled() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 8)?;
+
+    plane.box_styled(NcStyle::Bold, 0, 2, 3, NcBoxMask::None, NcBoxGlyphs::Heavy)?;
+    assert_eq!["┏━━┓", plane.contents(Some(0), Some(0), Some(1), Some(4))?];
+    assert_eq!["┗━━┛", plane.contents(Some(2), Some(0), Some(1), Some(4))?];
+
+    plane.cursor_move_yx(0, 4)?;
+    plane.box_styled(NcStyle::None, 0, 2, 7, NcBoxMask::None, NcBoxGlyphs::Light)?;
+    assert_eq!["│", plane.contents(Some(1), Some(4), Some(1), Some(1))?];
+    assert_eq!["│", plane.contents(Some(1), Some(7), Some(1), Some(1))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn fg_bg_color() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 1)?;
+
+    plane.set_fg_default();
+    plane.set_bg_default();
+    assert_eq![None, plane.fg_color()];
+    assert_eq![None, plane.bg_color()];
+
+    plane.set_fg_rgb(0x112233);
+    plane.set_bg_rgb(0x000000);
+    assert_eq![Some(0x112233.into()), plane.fg_color()];
+    assert_eq![Some(0x000000.into()), plane.bg_color()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn move_rel_clamped() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let plane = NcPlane::new_child(parent, &NcPlaneOptions::new(2, 3, 4, 5))?;
+
+    // fully inside the parent
+    assert_eq![(4, -3), plane.move_rel_clamped(8, -8, false)?];
+    assert_eq![(6, 0), plane.yx()];
+    assert_eq![(0, 15), plane.move_rel_clamped(1, 30, false)?];
+    assert_eq![(6, 15), plane.yx()];
+
+    // partially inside the parent
+    assert_eq![(3, 4), plane.move_rel_clamped(3, 9, true)?];
+    assert_eq![(9, 19), plane.yx()];
+    assert_eq![(-12, -19), plane.move_rel_clamped(-30, -19, true)?];
+    assert_eq![(-3, 0), plane.yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn to_rgba_bytes() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 1)?;
+    plane.set_bg_rgb(0xFF0000);
+    plane.putstr_yx(Some(0), Some(0), " ")?;
+
+    for (order, red) in [
+        (NcChannelOrder::Rgba, [0xFF, 0, 0, 0xFF]),
+        (NcChannelOrder::Bgra, [0, 0, 0xFF, 0xFF]),
+        (NcChannelOrder::Argb, [0xFF, 0xFF, 0, 0]),
+    ] {
+        let (bytes, pxdim_y, pxdim_x) = plane.to_rgba_bytes(NcBlitter::Ascii, None, order)?;
+        assert_eq![bytes.len(), (pxdim_y * pxdim_x * 4) as usize];
+        assert_eq![red, bytes[..4]];
+    }
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn try_dup() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+    let plane = NcPlane::new_child(parent, &NcPlaneOptions::new(1, 2, 3, 4))?;
+    plane.putstr("dup")?;
+    plane.on_resize(|_, _, _| ())?;
+
+    let dup = plane.try_dup()?;
+    assert_eq![plane.dim_yx(), dup.dim_yx()];
+    assert_eq!["dup", dup.contents(Some(0), Some(0), Some(1), Some(3))?];
+    assert![unsafe { crate::c_api::ncplane_userptr(dup) }.is_null()];
+    // the resize closure stays with the original plane only
+    assert![dup.resizecb().is_none()];
+    parent.resize_simple(12, 22)?;
+
+    let mut value = 7_u32;
+    let ptr = &mut value as *mut u32 as *mut core::ffi::c_void;
+    let dup2 = unsafe { plane.dup_with_userptr(ptr)? };
+    assert_eq![ptr, unsafe { crate::c_api::ncplane_userptr(dup2) }];
+
+    plane.clear_on_resize();
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn gradient_vertical_horizontal() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 5)?;
+    let (top, bottom) = (
+        NcChannels::from_rgb(0xFF0000, 0),
+        NcChannels::from_rgb(0x0000FF, 0),
+    );
+
+    assert_eq![
+        20,
+        plane.gradient_vertical(Some(0), Some(0), None, None, "x", 0, top, bottom)?
+    ];
+    assert_eq![
+        4,
+        plane.gradient_horizontal(Some(0), Some(2), None, Some(1), "x", 0, top, bottom)?
+    ];
+
+    // a single row, where the raw API fails with a vertical gradient
+    assert![plane
+        .gradient(
+            Some(1),
+            Some(0),
+            Some(1),
+            Some(5),
+            "x",
+            0,
+            top,
+            top,
+            bottom,
+            bottom
+        )
+        .is_err()];
+    assert_eq![
+        5,
+        plane.gradient_vertical(Some(1), Some(0), Some(1), Some(5), "x", 0, top, bottom)?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "libc")]
+fn flash_cell() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 3)?;
+    plane.set_channels(NcChannels::from_rgb(0x112233, 0x445566));
+    plane.putstr("key")?;
+    let original = plane.read_cell(0, 1)?.channels;
+
+    let highlight = NcChannels::from_rgb(0xFFFFFF, 0xFF0000);
+    let mut seen = Vec::new();
+    plane.flash_cell(0, 1, highlight, crate::NcTime::new(0, 0), |p| {
+        seen.push(p.read_cell(0, 1)?.channels);
+        Ok(())
+    })?;
+
+    assert_eq![vec![highlight, original], seen];
+    assert_eq![original, plane.read_cell(0, 1)?.channels];
+    assert_eq!["key", plane.contents(Some(0), Some(0), Some(1), None)?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_text_flow() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4)?;
+
+    plane.set_text_flow(NcTextFlow::Scroll);
+    assert![plane.scrolling_p() && !plane.autogrow_p()];
+
+    plane.set_text_flow(NcTextFlow::Grow);
+    assert![!plane.scrolling_p() && plane.autogrow_p()];
+    plane.putstr("growing")?;
+    assert_eq![(2, 7), plane.dim_yx()];
+
+    plane.set_text_flow(NcTextFlow::Clip);
+    assert![!plane.scrolling_p() && !plane.autogrow_p()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_ellipsized() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 12)?;
+
+    plane.cursor_move_yx(1, 2)?;
+    let text = "the quick brown fox jumps";
+    assert_eq![2, plane.putstr_ellipsized(text, (2, 9))?];
+    assert_eq![
+        "the quick",
+        plane.contents(Some(1), Some(2), Some(1), Some(9))?
+    ];
+    assert_eq![
+        "brown fo…",
+        plane.contents(Some(2), Some(2), Some(1), Some(9))?
+    ];
+    assert_eq!["", plane.contents(Some(3), Some(0), Some(1), None)?];
+
+    // the text fits, so there's no ellipsis
+    plane.erase();
+    assert_eq![1, plane.putstr_ellipsized("short", (2, 9))?];
+    assert_eq!["short", plane.contents(Some(0), Some(0), Some(1), None)?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn with_parentsize_bound() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 2, 3, 5, 7)?;
+    let overlay = NcPlane::with_parentsize_bound(parent)?;
+
+    assert_eq![(0, 0), overlay.yx()];
+    assert_eq![(5, 7), overlay.dim_yx()];
+    assert_eq![(2, 3), overlay.abs_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn highlight() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 12)?;
+    let normal = NcChannels::from_rgb(0xFFFFFF, 0x000000);
+    plane.set_channels(normal);
+    plane.putstr_yx(Some(0), Some(0), "a cat")?;
+    plane.putstr_yx(Some(1), Some(0), "concatenate")?;
+
+    let marked = NcChannels::from_rgb(0x000000, 0xFFFF00);
+    assert_eq![2, plane.highlight("cat", marked, None)?];
+
+    for (y, x) in [(0, 2), (0, 4), (1, 3), (1, 5)] {
+        assert_eq![marked, plane.read_cell(y, x)?.channels];
+    }
+    for (y, x) in [(0, 0), (0, 1), (1, 2), (1, 6)] {
+        assert_eq![normal, plane.read_cell(y, x)?.channels];
+    }
+    assert_eq![
+        "concatenate",
+        plane.contents(Some(1), Some(0), Some(1), None)?
+    ];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn try_dim_yx() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 7, 13)?;
+    assert_eq![(7, 13), plane.try_dim_yx()?];
+    assert_eq![plane.dim_yx(), plane.try_dim_yx()?];
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn center_child() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 40)?;
+    let child = NcPlane::new_child_sized(parent, 1, 2, 4, 10)?;
+
+    parent.center_child(child)?;
+    assert_eq![(8, 15), child.yx()];
+
+    let big = NcPlane::new_child_sized(parent, 0, 0, 21, 10)?;
+    assert![parent.center_child(big).is_err()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn put_hyperlink() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 20)?;
+
+    assert_eq![4, plane.put_hyperlink("docs", "https://notcurses.com")?];
+    assert_eq![(0, 4), plane.cursor_yx()];
+    assert_eq!["docs", plane.contents(Some(0), Some(0), Some(1), Some(4))?];
+    assert_eq!["", plane.contents(Some(0), Some(4), Some(1), Some(1))?];
+
+    assert![plane.put_hyperlink("do\x1b[2Jcs", "https://x").is_err()];
+    assert![plane.put_hyperlink("docs", "https://x\x07").is_err()];
+    assert_eq![(0, 4), plane.cursor_yx()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn sprixel_frames() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    if !nc.canpixel() {
+        unsafe { nc.stop()? };
+        return Ok(());
+    }
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 8)?;
+
+    let red = [0xFF, 0x00, 0x00, 0xFF].repeat(16 * 16);
+    let blue = [0x00, 0x00, 0xFF, 0xFF].repeat(16 * 16);
+
+    let mut sprixel = NcSprixel::new(plane);
+    assert![sprixel.is_empty()];
+    assert_eq![0, sprixel.add_frame(&red, 16, 16)?];
+    assert_eq![1, sprixel.add_frame(&blue, 16, 16)?];
+    assert![sprixel.add_frame(&blue, 32, 16).is_err()];
+    assert![sprixel.add_frame(&blue, u32::MAX, u32::MAX).is_err()];
+    assert_eq![2, sprixel.len()];
+
+    assert_eq![None, sprixel.current()];
+    sprixel.set_frame(0)?;
+    assert_eq![Some(0), sprixel.current()];
+    sprixel.set_frame(1)?;
+    sprixel.set_frame(0)?;
+    assert_eq![Some(0), sprixel.current()];
+    assert![sprixel.set_frame(2).is_err()];
+    assert_eq![Some(0), sprixel.current()];
+
+    drop(sprixel);
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn viewport_clamping() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 20)?;
+
+    let mut view = NcViewport::new(plane, 25);
+    assert_eq![0..10, view.visible_range()];
+
+    // top
+    view.scroll_by(-3);
+    assert_eq![0, view.offset()];
+    view.scroll_by(4);
+    view.scroll_by(-6);
+    assert_eq![0..10, view.visible_range()];
+
+    // bottom
+    view.scroll_by(12);
+    assert_eq![12..22, view.visible_range()];
+    view.scroll_by(isize::MAX);
+    assert_eq![15, view.offset()];
+    assert_eq![15..25, view.visible_range()];
+
+    // shrinking the dataset clamps the offset
+    view.set_total(12);
+    assert_eq![2..12, view.visible_range()];
+    view.set_total(4);
+    assert_eq![0..4, view.visible_range()];
+    view.scroll_by(1);
+    assert_eq![0, view.offset()];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putc_yx_opt() -> NcResult<()> {
+    let nc = unsafe { notcurses_init_test() };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 10)?;
+    let cell = NcCell::from_char(plane, 'x')?;
+
+    plane.cursor_move_yx(2, 3)?;
+    assert_eq![1, plane.putc_yx_opt(None, Some(7), &cell)?];
+    assert_eq![(2, 8), plane.cursor_yx()];
+    assert_eq![1, plane.putc_yx_opt(Some(0), None, &cell)?];
+    assert_eq![(0, 9), plane.cursor_yx()];
+    assert_eq![1, plane.putc_yx_opt(None, None, &cell)?];
+
+    assert_eq!["x", plane.contents(Some(2), Some(7), Some(1), Some(1))?];
+    assert_eq!["x", plane.contents(Some(0), Some(8), Some(1), Some(1))?];
+    assert_eq!["x", plane.contents(Some(0), Some(9), Some(1), Some(1))?];
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! `NcTextFlow`
+
+/// Indicates what [`NcPlane.set_text_flow`] configures an [`NcPlane`] to do
+/// with the output that reaches its boundary.
+///
+/// Each variant sets scrolling and autogrow in a consistent way, since
+/// enabling both at the same time makes the plane grow instead of scrolling.
+///
+/// # Default
+/// *[`NcTextFlow::Clip`]*
+///
+/// [`NcPlane`]: crate::NcPlane
+/// [`NcPlane.set_text_flow`]: crate::NcPlane#method.set_text_flow
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcTextFlow {
+    /// The output stops at the boundary of the plane
+    /// (scrolling and autogrow disabled).
+    #[default]
+    Clip,
+
+    /// The plane scrolls up once the output reaches its bottom-right corner
+    /// (scrolling enabled, autogrow disabled).
+    Scroll,
+
+    /// The plane grows to the right to accommodate the output
+    /// (autogrow enabled, scrolling disabled).
+    Grow,
+}
@@ -0,0 +1,74 @@
+//! `NcViewport`
+
+use core::ops::Range;
+
+use crate::NcPlane;
+
+/// Tracks which rows of a large list of items are visible in an [`NcPlane`].
+///
+/// The plane shows one item per row, starting at the item at the scroll
+/// offset, which is always kept between `0` and `total - height`.
+///
+/// The height is read from the plane each time it's needed, so the viewport
+/// adapts to plane resizes.
+#[derive(Debug)]
+pub struct NcViewport<'a> {
+    plane: &'a mut NcPlane,
+    offset: usize,
+    total: usize,
+}
+
+impl<'a> NcViewport<'a> {
+    /// New `NcViewport` over `total` items, scrolled to the top.
+    pub fn new(plane: &'a mut NcPlane, total: usize) -> Self {
+        Self { plane, offset: 0, total }
+    }
+
+    /// Returns a mutable reference to the wrapped plane.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the number of visible rows.
+    pub fn height(&self) -> usize {
+        self.plane.dim_y() as usize
+    }
+
+    /// Returns the scroll offset, the index of the first visible item.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the total number of items.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Sets the total number of items, clamping the scroll offset.
+    pub fn set_total(&mut self, total: usize) {
+        self.total = total;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// Returns the range of the indices of the visible items.
+    pub fn visible_range(&self) -> Range<usize> {
+        let offset = self.offset.min(self.max_offset());
+        offset..(offset + self.height()).min(self.total)
+    }
+
+    /// Scrolls by `delta` items, negative values scrolling up, clamping the
+    /// scroll offset between `0` and `total - height`.
+    pub fn scroll_by(&mut self, delta: isize) {
+        let offset = if delta < 0 {
+            self.offset.saturating_sub(delta.unsigned_abs())
+        } else {
+            self.offset.saturating_add(delta as usize)
+        };
+        self.offset = offset.min(self.max_offset());
+    }
+
+    /// Returns the maximum scroll offset.
+    fn max_offset(&self) -> usize {
+        self.total.saturating_sub(self.height())
+    }
+}
@@ -0,0 +1,153 @@
+//! `NcPlaneWriter`
+
+use crate::{c_api, cstring, NcError, NcPlane};
+use std::io;
+
+/// An [`io::Write`] adapter over an [`NcPlane`].
+///
+/// The written bytes are interpreted as UTF-8 and put on the plane at the
+/// cursor. A multibyte sequence split across two writes is kept until the
+/// rest of it arrives. Invalid sequences are replaced with `U+FFFD`.
+///
+/// By default [`flush`] does nothing. Use [`render_on_flush`] to render and
+/// rasterize the pile on every flush instead.
+///
+/// [`flush`]: NcPlaneWriter#method.flush
+/// [`render_on_flush`]: NcPlaneWriter#method.render_on_flush
+#[derive(Debug)]
+pub struct NcPlaneWriter<'a> {
+    plane: &'a mut NcPlane,
+    partial: Vec<u8>,
+    render_on_flush: bool,
+}
+
+impl<'a> NcPlaneWriter<'a> {
+    /// New `NcPlaneWriter` over `plane`.
+    pub fn new(plane: &'a mut NcPlane) -> Self {
+        Self { plane, partial: Vec::with_capacity(4), render_on_flush: false }
+    }
+
+    /// Sets whether [`flush`][NcPlaneWriter#method.flush] renders and
+    /// rasterizes the pile of the plane.
+    pub fn render_on_flush(mut self, render: bool) -> Self {
+        self.render_on_flush = render;
+        self
+    }
+
+    /// Returns a reference to the plane.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Returns the bytes of an incomplete UTF-8 sequence waiting for the
+    /// rest of it.
+    pub fn pending(&self) -> &[u8] {
+        &self.partial
+    }
+
+    /// Puts the valid UTF-8 `text` on the plane, one grapheme cluster at a
+    /// time, returning on error the number of bytes put before it.
+    fn put_text(&mut self, text: &[u8]) -> Result<(), (usize, io::Error)> {
+        let cs = cstring![text];
+        let (mut ptr, mut put) = (cs.as_ptr(), 0);
+        while put < text.len() {
+            let mut sbytes = 0;
+            let res =
+                unsafe { c_api::ffi::ncplane_putegc_yx(self.plane, -1, -1, ptr, &mut sbytes) };
+            if res < c_api::NCRESULT_OK {
+                let error = NcError::with_msg(res, "NcPlaneWriter.write()");
+                return Err((put, io::Error::new(io::ErrorKind::Other, error)));
+            }
+            if sbytes == 0 {
+                break;
+            }
+            put += sbytes;
+            ptr = unsafe { ptr.add(sbytes) };
+        }
+        Ok(())
+    }
+
+    /// Drops the `put` bytes from the pending ones, and returns the number of
+    /// bytes of the current write that were put before the `error`.
+    fn written_before(
+        &mut self,
+        pending: usize,
+        put: usize,
+        error: io::Error,
+    ) -> io::Result<usize> {
+        self.partial.drain(..put.min(pending));
+        if put > pending {
+            Ok(put - pending)
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl io::Write for NcPlaneWriter<'_> {
+    /// Puts the complete UTF-8 text on the plane, and keeps a trailing
+    /// incomplete sequence for the next write.
+    ///
+    /// Returns the number of bytes consumed from `buf`, which are all of them
+    /// unless a NUL byte is found, since it can't be put on the plane, or
+    /// the plane returns an error after some of them were already put.
+    ///
+    /// # Errors
+    /// If `buf` starts with a NUL byte, or if nothing could be put.
+    /// The pending bytes are kept for the next write.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let len = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+        if len == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "NcPlaneWriter.write(): NUL byte",
+            ));
+        }
+
+        let pending = self.partial.len();
+        let mut bytes = self.partial.clone();
+        bytes.extend_from_slice(&buf[..len]);
+
+        // the number of bytes put on the plane
+        let mut put = 0;
+        while put < bytes.len() {
+            let rest = &bytes[put..];
+            let (valid, invalid) = match core::str::from_utf8(rest) {
+                Ok(_) => (rest.len(), None),
+                Err(e) => (e.valid_up_to(), Some(e.error_len())),
+            };
+            if let Err((n, error)) = self.put_text(&rest[..valid]) {
+                return self.written_before(pending, put + n, error);
+            }
+            put += valid;
+            match invalid {
+                None => break,
+                Some(Some(invalid_len)) => {
+                    if let Err((_, error)) = self.put_text("\u{FFFD}".as_bytes()) {
+                        return self.written_before(pending, put, error);
+                    }
+                    put += invalid_len;
+                }
+                // an incomplete sequence at the end is kept for the next write
+                Some(None) => break,
+            }
+        }
+        self.partial.clear();
+        self.partial.extend_from_slice(&bytes[put..]);
+        Ok(len)
+    }
+
+    /// Renders and rasterizes the pile if
+    /// [`render_on_flush`][NcPlaneWriter#method.render_on_flush] is set.
+    fn flush(&mut self) -> io::Result<()> {
+        if self.render_on_flush {
+            self.plane
+                .render_raster()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        }
+        Ok(())
+    }
+}
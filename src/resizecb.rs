@@ -2,6 +2,9 @@
 
 use crate::{c_api::NcResult_i32, NcPlane};
 
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
 /// A callback function called when an [`NcPlane`] is resized.
 ///
 /// # See also
@@ -10,6 +13,34 @@ use crate::{c_api::NcResult_i32, NcPlane};
 /// - [`ncresizecb_to_c`][c_api::ncresizecb_to_c]
 pub type NcResizeCb = fn(&mut NcPlane) -> NcResult_i32;
 
+/// The type of the closures set with [`NcPlane.on_resize`].
+///
+/// [`NcPlane.on_resize`]: crate::NcPlane#method.on_resize
+pub(crate) type NcResizeFn = dyn FnMut(&mut NcPlane, (u32, u32), (u32, u32));
+
+/// The state of a closure set with [`NcPlane.on_resize`], stored in the
+/// plane's `userptr`.
+///
+/// [`NcPlane.on_resize`]: crate::NcPlane#method.on_resize
+pub(crate) struct NcResizeClosure {
+    pub(crate) cb: Box<NcResizeFn>,
+    /// The last seen dimensions of the parent plane.
+    pub(crate) last: (u32, u32),
+}
+
+/// The resize callback that calls the [`NcResizeClosure`] in the `userptr`.
+pub(crate) unsafe extern "C" fn ncresize_closure_trampoline(plane: *mut NcPlane) -> NcResult_i32 {
+    let state = crate::c_api::ncplane_userptr(plane) as *mut NcResizeClosure;
+    if state.is_null() {
+        return crate::c_api::NCRESULT_ERR;
+    }
+    let state = &mut *state;
+    let new = (*crate::c_api::ncplane_parent_const(plane)).dim_yx();
+    let old = core::mem::replace(&mut state.last, new);
+    (state.cb)(&mut *plane, old, new);
+    crate::c_api::NCRESULT_OK
+}
+
 pub(crate) mod c_api {
     use super::*;
 
@@ -59,7 +59,11 @@ mod core_impls {
         c_api::{NcRgb_u32, NcRgba_u32},
         NcRgb, NcRgba,
     };
-    use core::fmt;
+    use crate::NcError;
+    use core::{fmt, str::FromStr};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
 
     crate::from_primitive![NcRgb, NcRgb_u32];
     crate::unit_impl_from![NcRgb, NcRgb_u32];
@@ -121,6 +125,21 @@ mod core_impls {
         }
     }
 
+    /// Parses a web-style `"#RRGGBB"` hex string (the `#` is optional).
+    impl FromStr for NcRgb {
+        type Err = NcError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let hex = s.strip_prefix('#').unwrap_or(s);
+            if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(NcError::new_msg(&format!("NcRgb::from_str({:?})", s)));
+            }
+            NcRgb_u32::from_str_radix(hex, 16)
+                .map(Self)
+                .map_err(|_| NcError::new_msg(&format!("NcRgb::from_str({:?})", s)))
+        }
+    }
+
     //
 
     crate::from_primitive![NcRgba, NcRgba_u32];
@@ -196,6 +215,15 @@ mod core_impls {
             assert_eq!(rgba_arr, <[u8; 4]>::from(rgba));
             assert_eq!(rgba_tup, <(u8, u8, u8, u8)>::from(rgba));
         }
+
+        #[test]
+        fn rgb_from_str() {
+            assert_eq!(NcRgb(0x00FF00), "#00ff00".parse::<NcRgb>().unwrap());
+            assert_eq!(NcRgb(0x112233), "112233".parse::<NcRgb>().unwrap());
+            assert!("#12345".parse::<NcRgb>().is_err());
+            assert!("#12345G".parse::<NcRgb>().is_err());
+            assert!("+12345".parse::<NcRgb>().is_err());
+        }
     }
 }
 
@@ -1,7 +1,7 @@
 //!
 
 #[cfg(not(feature = "std"))]
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 
 /// A bitmask of styles.
 ///
@@ -121,21 +121,19 @@ mod core_impls {
 
 /// # Methods
 impl NcStyle {
+    /// The individual styles, in the order they are yielded by
+    /// [`iter`][NcStyle#method.iter].
+    const STYLES: [NcStyle; 5] = [
+        NcStyle::Italic,
+        NcStyle::Underline,
+        NcStyle::Undercurl,
+        NcStyle::Struck,
+        NcStyle::Bold,
+    ];
+
     /// Returns a `Vec` with all the styles contained in the current style.
     pub fn to_vec(&self) -> Vec<NcStyle> {
-        let mut v = vec![];
-        let styles = [
-            NcStyle::Italic,
-            NcStyle::Underline,
-            NcStyle::Undercurl,
-            NcStyle::Struck,
-            NcStyle::Bold,
-        ];
-        for s in &styles {
-            if self.has(*s) {
-                v.push(*s)
-            }
-        }
+        let mut v: Vec<NcStyle> = self.iter().collect();
         // only push None if there are no other styles present.
         if v.is_empty() {
             v.push(NcStyle::None)
@@ -161,6 +159,78 @@ impl NcStyle {
     pub fn unset(&mut self, other: impl Into<NcStyle>) {
         self.0 &= !other.into().0
     }
+
+    /// Returns true if all the `other` styles are contained in the current style.
+    ///
+    /// Same as [`has`][NcStyle#method.has].
+    #[inline]
+    pub fn contains(self, other: impl Into<NcStyle>) -> bool {
+        self.has(other)
+    }
+
+    /// Inserts the `other` styles in the current style.
+    ///
+    /// Same as [`set`][NcStyle#method.set].
+    #[inline]
+    pub fn insert(&mut self, other: impl Into<NcStyle>) {
+        self.set(other)
+    }
+
+    /// Removes the `other` styles from the current style.
+    ///
+    /// Same as [`unset`][NcStyle#method.unset].
+    #[inline]
+    pub fn remove(&mut self, other: impl Into<NcStyle>) {
+        self.unset(other)
+    }
+
+    /// Toggles the `other` styles in the current style.
+    #[inline]
+    pub fn toggle(&mut self, other: impl Into<NcStyle>) {
+        self.0 ^= other.into().0
+    }
+
+    /// Returns an iterator over the individual styles contained in the
+    /// current style.
+    ///
+    /// Unlike [`to_vec`][NcStyle#method.to_vec], it doesn't yield
+    /// [`None`][NcStyle::None] when there are no styles.
+    pub fn iter(self) -> impl Iterator<Item = NcStyle> {
+        Self::STYLES.into_iter().filter(move |s| self.has(*s))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NcStyle;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn insert_remove_toggle() {
+        let mut style = NcStyle::None;
+        assert_eq![0, style.iter().count()];
+
+        for (i, s) in NcStyle::STYLES.into_iter().enumerate() {
+            assert![!style.contains(s)];
+            style.insert(s);
+            assert![style.contains(s)];
+            assert_eq![i + 1, style.iter().count()];
+        }
+        assert_eq![NcStyle::STYLES.to_vec(), style.iter().collect::<Vec<_>>()];
+
+        for s in NcStyle::STYLES {
+            style.remove(s);
+            assert![!style.contains(s)];
+        }
+        assert_eq![NcStyle::None, style];
+
+        style.toggle(NcStyle::Bold | NcStyle::Italic);
+        assert![style.contains(NcStyle::Bold | NcStyle::Italic)];
+        style.toggle(NcStyle::Bold);
+        assert_eq![vec![NcStyle::Italic], style.iter().collect::<Vec<_>>()];
+    }
 }
 
 pub(crate) mod c_api {